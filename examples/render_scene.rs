@@ -0,0 +1,73 @@
+use std::{fs::File, io::BufWriter};
+
+use lightwalk::prelude::*;
+
+#[derive(Clone, Copy)]
+enum Color {
+    Red,
+    Blue,
+    Grey,
+}
+
+// The state of the SDF is used as its material when rendering, so we describe how each color
+// reacts to light.
+impl Material<f32> for Color {
+    fn color(&self) -> [f32; 3] {
+        match self {
+            Color::Red => [0.8, 0.1, 0.1],
+            Color::Blue => [0.1, 0.2, 0.8],
+            Color::Grey => [0.6, 0.6, 0.6],
+        }
+    }
+}
+
+fn main() -> std::io::Result<()> {
+    let scene = sphere()
+        .translate(&[-1.2, 0.0, 0.0])
+        .bind(Color::Red)
+        .add(
+            cube()
+                .scale(1.5)
+                .round(0.1)
+                .translate(&[1.2, 0.0, 0.0])
+                .bind(Color::Blue),
+        )
+        .add(
+            Plane::new([0.0, 1.0, 0.0])
+                .translate(&[0.0, -1.0, 0.0])
+                .bind(Color::Grey),
+        );
+
+    let marcher = Marcher::new(scene, 100.0, 0.001, 256);
+
+    let camera = Camera::look_at(
+        [0.0, 2.0, -6.0],
+        [0.0, 0.0, 0.0],
+        [0.0, 1.0, 0.0],
+        Projection::Perspective {
+            fov: 45_f32.to_radians(),
+        },
+        [640, 360],
+    );
+
+    let mut lighting = Lighting::new(
+        vec![
+            Light::Ambient {
+                color: [1.0; 3],
+                intensity: 0.1,
+            },
+            Light::Directional {
+                direction: [-1.0, -2.0, 1.0],
+                color: [1.0, 0.95, 0.9],
+                intensity: 1.0,
+            },
+        ],
+        [0.05, 0.05, 0.1],
+    );
+
+    lighting.shadow_softness = Some(16.0);
+    lighting.ambient_occlusion = Some(0.05);
+
+    let image = marcher.render_lit(&camera, &lighting);
+    image.write_ppm(BufWriter::new(File::create("scene.ppm")?))
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use num::Float;
+
+use super::Contours;
+use crate::{Sdf, grid::Grid};
+
+/// The offset of each corner from the minimum corner of the cell, counter-clockwise.
+const CORNERS: [[usize; 2]; 4] = [[0, 0], [1, 0], [1, 1], [0, 1]];
+
+pub(crate) fn marching_squares<Scalar: Float, State>(
+    sdf: &impl Sdf<Scalar, 2, State>,
+    grid: &Grid<Scalar, 2>,
+) -> Contours<Scalar> {
+    let samples = grid.sample(sdf);
+
+    // Points on the border of the grid are pushed outside of the shape to close the outlines.
+    let value = |point: [usize; 2]| {
+        let value = samples.value(point);
+
+        if (0..2).any(|i| point[i] == 0 || point[i] == grid.resolution[i]) {
+            value.max(Scalar::zero())
+        } else {
+            value
+        }
+    };
+
+    // Crossings are identified by the grid point of their edge closest to the minimum and the
+    // axis of the edge. Each crossing maps to the next one along the outline.
+    let mut next: HashMap<([usize; 2], usize), ([usize; 2], usize)> = HashMap::new();
+    let mut positions: HashMap<([usize; 2], usize), [Scalar; 2]> = HashMap::new();
+
+    for cell in grid.cells() {
+        let corners: [[usize; 2]; 4] =
+            CORNERS.map(|offset| std::array::from_fn(|i| cell[i] + offset[i]));
+        let values = corners.map(value);
+        let inside = values.map(|value| value < Scalar::zero());
+
+        let mut crossing = |edge: usize| {
+            let (start, end) = (edge, (edge + 1) % 4);
+            let (start, end) = if corners[start] < corners[end] {
+                (start, end)
+            } else {
+                (end, start)
+            };
+
+            let axis = if corners[start][0] != corners[end][0] {
+                0
+            } else {
+                1
+            };
+            let key = (corners[start], axis);
+
+            positions.entry(key).or_insert_with(|| {
+                let t = values[start] / (values[start] - values[end]);
+
+                let from = grid.point(corners[start]);
+                let to = grid.point(corners[end]);
+
+                std::array::from_fn(|i| from[i] + (to[i] - from[i]) * t)
+            });
+
+            key
+        };
+
+        // Walk the corners counter-clockwise. Each run of inside corners produces a segment from
+        // the edge leaving the run to the edge entering it, keeping the inside on its left. On
+        // saddles, the two inside corners are kept separated.
+        for entry in 0..4 {
+            if inside[entry] || !inside[(entry + 1) % 4] {
+                continue;
+            }
+
+            let mut exit = (entry + 1) % 4;
+            while inside[(exit + 1) % 4] {
+                exit = (exit + 1) % 4;
+            }
+
+            let from = crossing(exit);
+            let to = crossing(entry);
+            next.insert(from, to);
+        }
+    }
+
+    let mut contours = Contours::default();
+
+    while let Some(&start) = next.keys().next() {
+        let mut polyline = Vec::new();
+        let mut current = start;
+
+        while let Some(following) = next.remove(&current) {
+            polyline.push(positions[&current]);
+            current = following;
+        }
+
+        contours.polylines.push(polyline);
+    }
+
+    contours
+}
@@ -0,0 +1,72 @@
+mod marching_squares;
+mod svg;
+
+use num::Float;
+
+use crate::{Sdf, SdfState, grid::Grid};
+
+/// A set of closed polylines outlining the surface of a 2D SDF. Each polyline goes
+/// counter-clockwise around the inside of the shape, so holes go clockwise. The last point of a
+/// polyline connects back to the first one.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Contours<Scalar: Float, State = ()> {
+    pub polylines: Vec<Vec<[Scalar; 2]>>,
+    /// The state of the SDF on each polyline, if it was requested with [`Contours::with_states`].
+    pub states: Option<Vec<State>>,
+}
+
+impl<Scalar: Float, State> Default for Contours<Scalar, State> {
+    fn default() -> Self {
+        Self {
+            polylines: Vec::new(),
+            states: None,
+        }
+    }
+}
+
+impl<Scalar: Float, State> Contours<Scalar, State> {
+    /// Evaluates the state of the SDF on the first point of every polyline.
+    pub fn with_states<NewState: SdfState>(
+        self,
+        sdf: &impl Sdf<Scalar, 2, NewState>,
+    ) -> Contours<Scalar, NewState> {
+        let states = self
+            .polylines
+            .iter()
+            .map(|polyline| sdf.state(&polyline[0]))
+            .collect();
+
+        Contours {
+            polylines: self.polylines,
+            states: Some(states),
+        }
+    }
+}
+
+/// Extracts the outline of a 2D SDF inside of a grid.
+pub trait SdfContourOperations<Scalar: Float, State: SdfState>:
+    Sdf<Scalar, 2, State> + Sized
+{
+    /// Extracts the outline of the SDF with the marching squares algorithm. The border of the
+    /// grid is considered outside of the shape, so shapes going past it are clipped and their
+    /// outline stays closed.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use lightwalk::prelude::*;
+    ///
+    /// let grid = Grid::new([-2.0; 2], [2.0; 2], [64; 2]);
+    /// let contours = box_n([0.5, 1.0]).marching_squares(&grid);
+    /// ```
+    #[inline]
+    fn marching_squares(&self, grid: &Grid<Scalar, 2>) -> Contours<Scalar> {
+        marching_squares::marching_squares(self, grid)
+    }
+}
+
+impl<T, Scalar: Float, State: SdfState> SdfContourOperations<Scalar, State> for T where
+    T: Sdf<Scalar, 2, State>
+{
+}
@@ -0,0 +1,102 @@
+use std::io::Write;
+
+use num::Float;
+
+use super::Contours;
+use crate::{
+    SdfState,
+    math::to_f32,
+    render::{Material, to_rgb8},
+};
+
+impl<Scalar: Float, State> Contours<Scalar, State> {
+    /// Encodes the outlines as an SVG image, drawing them as black strokes without any fill. The
+    /// Y axis is flipped so the image is not upside down.
+    pub fn write_svg(&self, writer: impl Write) -> std::io::Result<()> {
+        let style = "fill=\"none\" stroke=\"black\" stroke-width=\"1\" \
+                     vector-effect=\"non-scaling-stroke\"";
+
+        self.write_svg_paths(
+            writer,
+            &[(style.to_string(), (0..self.polylines.len()).collect())],
+        )
+    }
+
+    /// Writes one path per group of polylines, each group being a style and the indices of its
+    /// polylines.
+    fn write_svg_paths(
+        &self,
+        mut writer: impl Write,
+        groups: &[(String, Vec<usize>)],
+    ) -> std::io::Result<()> {
+        let [min_x, min_y, max_x, max_y] = self
+            .polylines
+            .iter()
+            .flatten()
+            .fold(None, |acc: Option<[Scalar; 4]>, [x, y]| {
+                Some(match acc {
+                    Some([min_x, min_y, max_x, max_y]) => {
+                        [min_x.min(*x), min_y.min(*y), max_x.max(*x), max_y.max(*y)]
+                    }
+                    None => [*x, *y, *x, *y],
+                })
+            })
+            .unwrap_or([Scalar::zero(); 4])
+            .map(to_f32);
+
+        writeln!(
+            writer,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"{min_x} {} {} {}\">",
+            -max_y,
+            max_x - min_x,
+            max_y - min_y,
+        )?;
+
+        for (style, polylines) in groups {
+            write!(writer, "  <path fill-rule=\"evenodd\" {style} d=\"")?;
+
+            for &polyline in polylines {
+                for (i, point) in self.polylines[polyline].iter().enumerate() {
+                    let [x, y] = point.map(to_f32);
+                    let command = if i == 0 { "M" } else { "L" };
+                    write!(writer, "{command}{x} {} ", -y)?;
+                }
+
+                write!(writer, "Z")?;
+            }
+
+            writeln!(writer, "\"/>")?;
+        }
+
+        writeln!(writer, "</svg>")?;
+        writer.flush()
+    }
+}
+
+impl<Scalar: Float, State: SdfState + Material<Scalar>> Contours<Scalar, State> {
+    /// Encodes the outlines as an SVG image, filling them with the color of the material of their
+    /// state. Outlines sharing the same color are drawn as a single path so that holes are left
+    /// empty. Contours without states are drawn as with [`Contours::write_svg`].
+    pub fn write_colored_svg(&self, writer: impl Write) -> std::io::Result<()> {
+        let Some(states) = &self.states else {
+            return self.write_svg(writer);
+        };
+
+        let mut groups: Vec<([u8; 3], Vec<usize>)> = Vec::new();
+        for (i, state) in states.iter().enumerate() {
+            let color = to_rgb8(state.color());
+
+            match groups.iter_mut().find(|(other, _)| *other == color) {
+                Some((_, polylines)) => polylines.push(i),
+                None => groups.push((color, vec![i])),
+            }
+        }
+
+        let groups: Vec<_> = groups
+            .into_iter()
+            .map(|([r, g, b], polylines)| (format!("fill=\"rgb({r},{g},{b})\""), polylines))
+            .collect();
+
+        self.write_svg_paths(writer, &groups)
+    }
+}
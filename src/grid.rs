@@ -0,0 +1,119 @@
+use num::Float;
+
+use crate::Sdf;
+
+/// A regular grid of cells spanning an axis aligned bounding box. This is the sampling
+/// infrastructure shared by the meshers and contour extractors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Grid<Scalar: Float, const DIM: usize> {
+    pub min: [Scalar; DIM],
+    pub max: [Scalar; DIM],
+    /// The number of cells along each axis. There is one more grid point than cells on each axis.
+    pub resolution: [usize; DIM],
+}
+
+impl<Scalar: Float, const DIM: usize> Grid<Scalar, DIM> {
+    pub fn new(min: [Scalar; DIM], max: [Scalar; DIM], resolution: [usize; DIM]) -> Self {
+        if resolution.contains(&0) {
+            panic!("Cannot define a grid without any cell along an axis");
+        }
+
+        Self {
+            min,
+            max,
+            resolution,
+        }
+    }
+
+    /// Returns the size of a single cell along each axis.
+    #[inline]
+    pub fn cell_size(&self) -> [Scalar; DIM] {
+        std::array::from_fn(|i| {
+            (self.max[i] - self.min[i]) / Scalar::from(self.resolution[i]).unwrap()
+        })
+    }
+
+    /// Returns the number of grid points along each axis.
+    #[inline]
+    pub fn point_count(&self) -> [usize; DIM] {
+        self.resolution.map(|e| e + 1)
+    }
+
+    /// Returns the world-space position of a grid point.
+    #[inline]
+    pub fn point(&self, index: [usize; DIM]) -> [Scalar; DIM] {
+        let cell_size = self.cell_size();
+        std::array::from_fn(|i| self.min[i] + cell_size[i] * Scalar::from(index[i]).unwrap())
+    }
+
+    /// Iterates over the indices of all the grid points, with the first axis varying the fastest.
+    #[inline]
+    pub fn points(&self) -> impl Iterator<Item = [usize; DIM]> + use<Scalar, DIM> {
+        indices(self.point_count())
+    }
+
+    /// Iterates over the indices of all the cells, with the first axis varying the fastest. A cell
+    /// shares its index with its grid point closest to the minimum of the grid.
+    #[inline]
+    pub fn cells(&self) -> impl Iterator<Item = [usize; DIM]> + use<Scalar, DIM> {
+        indices(self.resolution)
+    }
+
+    /// Evaluates the SDF on every grid point.
+    pub fn sample<State>(&self, sdf: &impl Sdf<Scalar, DIM, State>) -> SampledGrid<Scalar, DIM> {
+        let values = self
+            .points()
+            .map(|index| sdf.distance_from_slice(&self.point(index)))
+            .collect();
+
+        SampledGrid {
+            grid: *self,
+            values,
+        }
+    }
+}
+
+/// The distances of a SDF sampled on every point of a [`Grid`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct SampledGrid<Scalar: Float, const DIM: usize> {
+    grid: Grid<Scalar, DIM>,
+    values: Vec<Scalar>,
+}
+
+impl<Scalar: Float, const DIM: usize> SampledGrid<Scalar, DIM> {
+    #[inline]
+    pub fn grid(&self) -> &Grid<Scalar, DIM> {
+        &self.grid
+    }
+
+    /// Returns the distances of every grid point, in the order of [`Grid::points`].
+    #[inline]
+    pub fn values(&self) -> &[Scalar] {
+        &self.values
+    }
+
+    /// Returns the distance sampled at a given grid point.
+    #[inline]
+    pub fn value(&self, index: [usize; DIM]) -> Scalar {
+        let point_count = self.grid.point_count();
+
+        let mut flat_index = 0;
+        for i in (0..DIM).rev() {
+            flat_index = flat_index * point_count[i] + index[i];
+        }
+
+        self.values[flat_index]
+    }
+}
+
+fn indices<const DIM: usize>(count: [usize; DIM]) -> impl Iterator<Item = [usize; DIM]> {
+    let total = count.iter().product();
+
+    (0..total).map(move |mut flat_index| {
+        std::array::from_fn(|i| {
+            let index = flat_index % count[i];
+            flat_index /= count[i];
+            index
+        })
+    })
+}
@@ -1,8 +1,15 @@
 #![doc = include_str!("../docs/lib.rs.md")]
 
+pub mod contour;
+pub mod grid;
 pub mod marcher;
+mod math;
+pub mod mesh;
 pub mod prelude;
+pub mod render;
 pub mod sdf;
+#[cfg(feature = "serde")]
+mod serde_array;
 
 use std::ops::Deref;
 
@@ -51,12 +58,14 @@ pub trait Sdf<Scalar: Float, const DIM: usize, State = ()> {
     #[inline]
     fn gradient(&self, point: impl Into<[Scalar; DIM]>, epsilon: Scalar) -> [Scalar; DIM] {
         let point = point.into();
+        let distance = self.distance_from_slice(&point);
+        let inv_epsilon = Scalar::one() / epsilon;
 
         std::array::from_fn(|i| {
             let mut point = point;
             point[i] = point[i] + epsilon;
 
-            self.distance_from_slice(&point)
+            (self.distance_from_slice(&point) - distance) * inv_epsilon
         })
     }
 
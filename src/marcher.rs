@@ -1,88 +1,289 @@
-use std::{fs::File, io::Write, ops::Range};
+use std::{io::Write, marker::PhantomData};
 
-use crate::Sdf;
+use crate::{
+    Sdf, SdfState,
+    math::{add, length, normalize, scale, sub},
+    render::{Camera, Framebuffer, Lighting, Material, to_rgb8},
+};
 use num::Float;
 
+/// A ray cast from an origin towards a direction. The direction is expected to be normalized, as
+/// the marcher uses it to step through space by the distance returned by the SDF.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Ray<Scalar: Float, const DIM: usize> {
     pub origin: [Scalar; DIM],
     pub direction: [Scalar; DIM],
 }
 
-pub struct Marcher<Scalar: Float, T, const DIM: usize>
+impl<Scalar: Float, const DIM: usize> Ray<Scalar, DIM> {
+    /// Returns the point found at a given distance along the ray.
+    #[inline]
+    pub fn at(&self, distance: Scalar) -> [Scalar; DIM] {
+        std::array::from_fn(|i| self.origin[i] + self.direction[i] * distance)
+    }
+}
+
+/// Marches rays through an SDF. As the marcher also keeps track of the state type of the SDF, it
+/// cannot be built with a struct literal: use [`Marcher::new`] instead, the settings staying
+/// public so they can be tweaked afterwards.
+pub struct Marcher<Scalar: Float, T, const DIM: usize, State: SdfState = ()>
 where
-    T: Sdf<Scalar, DIM>,
+    T: Sdf<Scalar, DIM, State>,
 {
     pub max_ray_distance: Scalar,
     pub surface_distance: Scalar,
     pub max_iter_count: u32,
 
     pub surface: T,
+    _marker: PhantomData<State>,
 }
 
-pub struct Collision<Scalar: Float> {
+/// The hit record returned when a ray hits the surface of the SDF.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Collision<Scalar: Float, const DIM: usize, State: SdfState = ()> {
+    /// The distance travelled along the ray before hitting the surface.
     pub distance: Scalar,
+    /// The world-space position of the hit.
+    pub position: [Scalar; DIM],
+    /// The normal of the surface at the hit position.
+    pub normal: [Scalar; DIM],
+    /// The state of the SDF at the hit position.
+    pub state: State,
+    /// The number of steps taken before hitting the surface.
+    pub iterations: u32,
+    /// The distance to the surface returned by the SDF on the last step. This is always less than
+    /// the surface distance of the marcher.
+    pub residual: Scalar,
 }
 
-impl<Scalar: Float, T, const DIM: usize> Marcher<Scalar, T, DIM>
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Marcher<Scalar, T, DIM, State>
 where
-    T: Sdf<Scalar, DIM>,
+    T: Sdf<Scalar, DIM, State>,
 {
-    pub fn march(&self, ray: &Ray<Scalar, DIM>) -> Option<Collision<Scalar>> {
+    #[inline]
+    pub fn new(
+        surface: T,
+        max_ray_distance: Scalar,
+        surface_distance: Scalar,
+        max_iter_count: u32,
+    ) -> Self {
+        Self {
+            max_ray_distance,
+            surface_distance,
+            max_iter_count,
+            surface,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Marches along the ray until either the surface is hit, the ray travels further than the
+    /// maximum ray distance or the maximum number of iterations is reached. The normal of the hit
+    /// record is computed using the surface distance as the epsilon of the finite differences.
+    pub fn march(&self, ray: &Ray<Scalar, DIM>) -> Option<Collision<Scalar, DIM, State>> {
         let mut total_distance = Scalar::zero();
-        let mut position = ray.origin;
 
-        for _ in 0..self.max_iter_count {
+        for iteration in 0..self.max_iter_count {
             if total_distance > self.max_ray_distance {
                 return None;
             }
 
+            let position = ray.at(total_distance);
             let distance = self.surface.distance_from_slice(&position);
 
             if distance < self.surface_distance {
+                let (residual, state) = self.surface.distance_and_state(position);
+
                 return Some(Collision {
                     distance: total_distance,
+                    position,
+                    normal: self.surface.normal(position, self.surface_distance),
+                    state,
+                    iterations: iteration + 1,
+                    residual,
                 });
             }
 
             total_distance = total_distance + distance;
+        }
+
+        None
+    }
+
+    /// Returns whether the straight segment between two points crosses the surface. The starting
+    /// point should be moved slightly away from the surface (along its normal for example),
+    /// otherwise it is always considered occluded.
+    pub fn occluded(&self, from: &[Scalar; DIM], to: &[Scalar; DIM]) -> bool {
+        let offset = sub(to, from);
+        let max_distance = length(&offset);
+
+        let ray = Ray {
+            origin: *from,
+            direction: normalize(&offset),
+        };
 
-            for (i, axis) in position.iter_mut().enumerate() {
-                *axis = *axis + ray.direction[i];
+        let mut total_distance = Scalar::zero();
+
+        for _ in 0..self.max_iter_count {
+            if total_distance >= max_distance {
+                return false;
+            }
+
+            let distance = self.surface.distance_from_slice(&ray.at(total_distance));
+
+            if distance < self.surface_distance {
+                return true;
             }
+
+            total_distance = total_distance + distance;
         }
 
-        None
+        false
     }
 
-    pub fn trace_to_ppm(&self, mut file: File, res: usize) -> std::io::Result<()> {
-        file.set_len(0)?;
-        {
-            let header = format!("P6\n{0}{0}\n255\n", res);
-            file.write_all(header.as_bytes())?;
+    /// Returns how much light reaches the origin of the ray from its direction, from 0 when fully
+    /// in shadow to 1 when fully lit. Rays passing close to the surface are partially shadowed
+    /// using Inigo Quilez's `k * h / t` estimator, where `softness` is `k`: the lower it is, the
+    /// wider the penumbra. Like for [`Marcher::occluded`], the origin should be moved slightly
+    /// away from the surface.
+    pub fn soft_shadow(
+        &self,
+        ray: &Ray<Scalar, DIM>,
+        max_distance: Scalar,
+        softness: Scalar,
+    ) -> Scalar {
+        let max_distance = max_distance.min(self.max_ray_distance);
+
+        let mut light = Scalar::one();
+        let mut total_distance = self.surface_distance;
+
+        for _ in 0..self.max_iter_count {
+            if total_distance >= max_distance {
+                break;
+            }
+
+            let distance = self.surface.distance_from_slice(&ray.at(total_distance));
+
+            if distance < self.surface_distance {
+                return Scalar::zero();
+            }
+
+            light = light.min(softness * distance / total_distance);
+            total_distance = total_distance + distance;
         }
 
-        for [_x, _y] in iter_pairs(&[0..res, 0..res]) {
-            #[allow(unused)]
-            let ray = Ray {
-                origin: todo!(),
-                direction: todo!(),
-            };
+        light.max(Scalar::zero()).min(Scalar::one())
+    }
 
-            let color = match self.march(&ray) {
-                Some(_collision) => [255; 3],
-                None => [0; 3],
-            };
+    /// Estimates how exposed a point of the surface is to ambient light by sampling the distance
+    /// field along the normal, from 0 when fully occluded to 1 when fully exposed. Samples are
+    /// taken every `sample_spacing` units, and closer samples weigh more than further ones.
+    pub fn ambient_occlusion(
+        &self,
+        position: &[Scalar; DIM],
+        normal: &[Scalar; DIM],
+        sample_count: u32,
+        sample_spacing: Scalar,
+    ) -> Scalar {
+        let half = Scalar::from(0.5).unwrap();
+
+        let mut occlusion = Scalar::zero();
+        let mut weight = half;
+
+        for i in 1..=sample_count {
+            let sample_distance = sample_spacing * Scalar::from(i).unwrap();
+            let sample = add(position, &scale(normal, sample_distance));
+
+            let distance = self.surface.distance_from_slice(&sample);
 
-            file.write_all(&color)?;
+            // An unoccluded sample is as far from the surface as it is from the position, so the
+            // difference is how much closer the rest of the surface is.
+            occlusion = occlusion + weight * (sample_distance - distance) / sample_spacing;
+            weight = weight * half;
         }
 
-        Ok(())
+        (Scalar::one() - occlusion)
+            .max(Scalar::zero())
+            .min(Scalar::one())
     }
 }
 
-fn iter_pairs(range: &[Range<usize>; 2]) -> impl Iterator<Item = [usize; 2]> {
-    // clones are cheap 16 byte stack copies
-    range[1]
-        .clone()
-        .flat_map(|x| range[0].clone().map(move |y| [x, y]))
+/// The number of samples taken along the normal when rendering with ambient occlusion.
+const AMBIENT_OCCLUSION_SAMPLES: u32 = 5;
+
+impl<Scalar: Float, T, State: SdfState> Marcher<Scalar, T, 3, State>
+where
+    T: Sdf<Scalar, 3, State>,
+{
+    /// Marches one ray per pixel of the camera and writes the value returned by `shade` for each
+    /// of them in a framebuffer of the camera's resolution.
+    pub fn render<Pixel>(
+        &self,
+        camera: &Camera<Scalar>,
+        mut shade: impl FnMut(&Ray<Scalar, 3>, Option<Collision<Scalar, 3, State>>) -> Pixel,
+    ) -> Framebuffer<Pixel> {
+        Framebuffer::from_fn(camera.resolution, |pixel| {
+            let ray = camera.ray(pixel);
+            let collision = self.march(&ray);
+
+            shade(&ray, collision)
+        })
+    }
+
+    /// Renders a lit RGB image of the SDF seen from the camera. The state of the SDF is used as the
+    /// material of the surface. Shadows and ambient occlusion are added if enabled on the lighting.
+    pub fn render_lit(
+        &self,
+        camera: &Camera<Scalar>,
+        lighting: &Lighting<Scalar>,
+    ) -> Framebuffer<[u8; 3]>
+    where
+        State: Material<Scalar>,
+    {
+        self.render(camera, |ray, collision| {
+            let Some(collision) = collision else {
+                return to_rgb8(lighting.background);
+            };
+
+            // Move the origin of the shadow rays away from the surface so they do not hit it
+            // straight away.
+            let origin = add(
+                &collision.position,
+                &scale(
+                    &collision.normal,
+                    self.surface_distance * Scalar::from(2.0).unwrap(),
+                ),
+            );
+
+            let color = lighting.shade_with(ray, &collision, |light| {
+                match light.incoming(&collision.position) {
+                    (None, _, _) => match lighting.ambient_occlusion {
+                        Some(spacing) => self.ambient_occlusion(
+                            &collision.position,
+                            &collision.normal,
+                            AMBIENT_OCCLUSION_SAMPLES,
+                            spacing,
+                        ),
+                        None => Scalar::one(),
+                    },
+                    (Some(direction), distance, _) => match lighting.shadow_softness {
+                        Some(softness) => {
+                            self.soft_shadow(&Ray { origin, direction }, distance, softness)
+                        }
+                        None => Scalar::one(),
+                    },
+                }
+            });
+
+            to_rgb8(color)
+        })
+    }
+
+    /// Renders a black and white silhouette of the SDF seen from the camera as a PPM image.
+    pub fn trace_to_ppm(&self, camera: &Camera<Scalar>, writer: impl Write) -> std::io::Result<()> {
+        self.render(camera, |_, collision| match collision {
+            Some(_) => [255; 3],
+            None => [0; 3],
+        })
+        .write_ppm(writer)
+    }
 }
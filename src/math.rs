@@ -0,0 +1,234 @@
+//! Small vector and matrix helpers over plain arrays shared by the marcher, the renderer, the
+//! meshers and the transformers. Matrices are stored as arrays of rows.
+
+use num::Float;
+
+#[inline]
+pub(crate) fn add<Scalar: Float, const DIM: usize>(
+    lhs: &[Scalar; DIM],
+    rhs: &[Scalar; DIM],
+) -> [Scalar; DIM] {
+    std::array::from_fn(|i| lhs[i] + rhs[i])
+}
+
+#[inline]
+pub(crate) fn sub<Scalar: Float, const DIM: usize>(
+    lhs: &[Scalar; DIM],
+    rhs: &[Scalar; DIM],
+) -> [Scalar; DIM] {
+    std::array::from_fn(|i| lhs[i] - rhs[i])
+}
+
+#[inline]
+pub(crate) fn scale<Scalar: Float, const DIM: usize>(
+    vector: &[Scalar; DIM],
+    factor: Scalar,
+) -> [Scalar; DIM] {
+    std::array::from_fn(|i| vector[i] * factor)
+}
+
+#[inline]
+pub(crate) fn dot<Scalar: Float, const DIM: usize>(
+    lhs: &[Scalar; DIM],
+    rhs: &[Scalar; DIM],
+) -> Scalar {
+    lhs.iter()
+        .zip(rhs.iter())
+        .fold(Scalar::zero(), |acc, (&lhs, &rhs)| acc + lhs * rhs)
+}
+
+#[inline]
+pub(crate) fn length<Scalar: Float, const DIM: usize>(vector: &[Scalar; DIM]) -> Scalar {
+    dot(vector, vector).sqrt()
+}
+
+/// Normalizes the vector. Null vectors are returned as is instead of being filled with NaNs.
+#[inline]
+pub(crate) fn normalize<Scalar: Float, const DIM: usize>(vector: &[Scalar; DIM]) -> [Scalar; DIM] {
+    let norm = length(vector);
+
+    if norm == Scalar::zero() {
+        return *vector;
+    }
+
+    scale(vector, Scalar::one() / norm)
+}
+
+/// Returns the squared distance from the point to the segment between `start` and `end`, by
+/// projecting the point on the segment and clamping the projection to its ends. Degenerate
+/// segments are treated as a single point.
+#[inline]
+pub(crate) fn segment_distance_squared<Scalar: Float, const DIM: usize>(
+    start: &[Scalar; DIM],
+    end: &[Scalar; DIM],
+    point: &[Scalar; DIM],
+) -> Scalar {
+    let edge = sub(end, start);
+    let offset = sub(point, start);
+
+    let length_squared = dot(&edge, &edge);
+    let t = if length_squared > Scalar::zero() {
+        (dot(&offset, &edge) / length_squared)
+            .max(Scalar::zero())
+            .min(Scalar::one())
+    } else {
+        Scalar::zero()
+    };
+
+    let projection = sub(&offset, &scale(&edge, t));
+
+    dot(&projection, &projection)
+}
+
+#[inline]
+pub(crate) fn cross<Scalar: Float>(lhs: &[Scalar; 3], rhs: &[Scalar; 3]) -> [Scalar; 3] {
+    [
+        lhs[1] * rhs[2] - lhs[2] * rhs[1],
+        lhs[2] * rhs[0] - lhs[0] * rhs[2],
+        lhs[0] * rhs[1] - lhs[1] * rhs[0],
+    ]
+}
+
+/// The number of sweeps of the Jacobi eigenvalue algorithm. Small matrices converge in a handful.
+const JACOBI_SWEEPS: usize = 8;
+
+#[inline]
+pub(crate) fn identity<Scalar: Float, const DIM: usize>() -> [[Scalar; DIM]; DIM] {
+    std::array::from_fn(|i| {
+        std::array::from_fn(|j| {
+            if i == j {
+                Scalar::one()
+            } else {
+                Scalar::zero()
+            }
+        })
+    })
+}
+
+#[inline]
+pub(crate) fn transpose<Scalar: Float, const DIM: usize>(
+    matrix: &[[Scalar; DIM]; DIM],
+) -> [[Scalar; DIM]; DIM] {
+    std::array::from_fn(|i| std::array::from_fn(|j| matrix[j][i]))
+}
+
+#[inline]
+pub(crate) fn mul_vector<Scalar: Float, const DIM: usize>(
+    matrix: &[[Scalar; DIM]; DIM],
+    vector: &[Scalar; DIM],
+) -> [Scalar; DIM] {
+    std::array::from_fn(|i| dot(&matrix[i], vector))
+}
+
+#[inline]
+pub(crate) fn mul_matrix<Scalar: Float, const DIM: usize>(
+    lhs: &[[Scalar; DIM]; DIM],
+    rhs: &[[Scalar; DIM]; DIM],
+) -> [[Scalar; DIM]; DIM] {
+    let rhs = transpose(rhs);
+
+    std::array::from_fn(|i| std::array::from_fn(|j| dot(&lhs[i], &rhs[j])))
+}
+
+/// Inverts the matrix with Gauss-Jordan elimination and partial pivoting, or returns `None` if it
+/// is singular.
+pub(crate) fn invert<Scalar: Float, const DIM: usize>(
+    matrix: &[[Scalar; DIM]; DIM],
+) -> Option<[[Scalar; DIM]; DIM]> {
+    let mut matrix = *matrix;
+    let mut inverse = identity();
+
+    for column in 0..DIM {
+        let pivot = (column..DIM).max_by(|&a, &b| {
+            matrix[a][column]
+                .abs()
+                .partial_cmp(&matrix[b][column].abs())
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })?;
+
+        if matrix[pivot][column] == Scalar::zero() {
+            return None;
+        }
+
+        matrix.swap(column, pivot);
+        inverse.swap(column, pivot);
+
+        let factor = Scalar::one() / matrix[column][column];
+        matrix[column] = scale(&matrix[column], factor);
+        inverse[column] = scale(&inverse[column], factor);
+
+        for row in 0..DIM {
+            if row == column {
+                continue;
+            }
+
+            let factor = matrix[row][column];
+            matrix[row] = sub(&matrix[row], &scale(&matrix[column], factor));
+            inverse[row] = sub(&inverse[row], &scale(&inverse[column], factor));
+        }
+    }
+
+    Some(inverse)
+}
+
+/// Diagonalizes a symmetric matrix with the Jacobi eigenvalue algorithm. The eigenvectors are
+/// returned as the columns of the second matrix.
+pub(crate) fn symmetric_eigen<Scalar: Float, const DIM: usize>(
+    mut matrix: [[Scalar; DIM]; DIM],
+) -> ([Scalar; DIM], [[Scalar; DIM]; DIM]) {
+    let mut vectors = identity();
+
+    for _ in 0..JACOBI_SWEEPS {
+        for p in 0..DIM {
+            for q in p + 1..DIM {
+                if matrix[p][q] == Scalar::zero() {
+                    continue;
+                }
+
+                let two = Scalar::from(2.0).unwrap();
+                let theta = (matrix[q][q] - matrix[p][p]) / (two * matrix[p][q]);
+                let t = theta.signum() / (theta.abs() + (theta * theta + Scalar::one()).sqrt());
+                let cos = Scalar::one() / (t * t + Scalar::one()).sqrt();
+                let sin = t * cos;
+
+                // Rotate both the rows and the columns p and q of the matrix.
+                for row in &mut matrix {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = cos * kp - sin * kq;
+                    row[q] = sin * kp + cos * kq;
+                }
+
+                let (row_p, row_q) = (matrix[p], matrix[q]);
+                matrix[p] = std::array::from_fn(|k| cos * row_p[k] - sin * row_q[k]);
+                matrix[q] = std::array::from_fn(|k| sin * row_p[k] + cos * row_q[k]);
+
+                for row in &mut vectors {
+                    let (kp, kq) = (row[p], row[q]);
+                    row[p] = cos * kp - sin * kq;
+                    row[q] = sin * kp + cos * kq;
+                }
+            }
+        }
+    }
+
+    (std::array::from_fn(|i| matrix[i][i]), vectors)
+}
+
+/// Returns the smallest singular value of the matrix, which is the smallest factor by which it
+/// stretches any vector.
+pub(crate) fn min_singular_value<Scalar: Float, const DIM: usize>(
+    matrix: &[[Scalar; DIM]; DIM],
+) -> Scalar {
+    let (eigenvalues, _) = symmetric_eigen(mul_matrix(&transpose(matrix), matrix));
+
+    eigenvalues
+        .iter()
+        .fold(Scalar::infinity(), |acc, e| acc.min(e.max(Scalar::zero())))
+        .sqrt()
+}
+
+/// Converts a scalar to a `f32`, used by the file encoders.
+#[inline]
+pub(crate) fn to_f32<Scalar: Float>(scalar: Scalar) -> f32 {
+    scalar.to_f32().unwrap_or(f32::NAN)
+}
@@ -0,0 +1,165 @@
+use num::Float;
+
+use super::{
+    Mesh, edge_crossing, normal_epsilon,
+    tables::{CORNERS, EDGES},
+};
+use crate::{
+    Sdf,
+    grid::Grid,
+    math::{dot, sub, symmetric_eigen},
+};
+
+/// Eigenvalues of the QEF smaller than this fraction of the largest one are ignored, which keeps
+/// the vertex on the mass point along the directions the normals do not constrain.
+const EIGENVALUE_THRESHOLD: f64 = 0.1;
+
+pub(crate) fn dual_contouring<Scalar: Float, State>(
+    sdf: &impl Sdf<Scalar, 3, State>,
+    grid: &Grid<Scalar, 3>,
+) -> Mesh<Scalar> {
+    let samples = grid.sample(sdf);
+    let epsilon = normal_epsilon(grid);
+    let [x_cells, y_cells, _] = grid.resolution;
+
+    let mut mesh = Mesh::default();
+    let mut cell_vertices: Vec<Option<u32>> = vec![None; grid.cells().count()];
+
+    let cell_index = |cell: [usize; 3]| cell[0] + x_cells * (cell[1] + y_cells * cell[2]);
+
+    // Place one vertex in every cell crossed by the surface, minimizing the distance to the
+    // tangent planes of the surface at each crossed edge.
+    for cell in grid.cells() {
+        let corners: [[usize; 3]; 8] =
+            CORNERS.map(|offset| std::array::from_fn(|i| cell[i] + offset[i]));
+        let values = corners.map(|corner| samples.value(corner));
+
+        let crossings: Vec<_> = EDGES
+            .iter()
+            .filter(|[start, end]| {
+                (values[*start] < Scalar::zero()) != (values[*end] < Scalar::zero())
+            })
+            .map(|[start, end]| {
+                let position = edge_crossing(
+                    grid,
+                    (corners[*start], values[*start]),
+                    (corners[*end], values[*end]),
+                );
+                (position, sdf.normal(position, epsilon))
+            })
+            .collect();
+
+        if crossings.is_empty() {
+            continue;
+        }
+
+        let min = grid.point(corners[0]);
+        let max = grid.point(corners[6]);
+
+        let position = solve_qef(&crossings);
+        let position = std::array::from_fn(|i| position[i].max(min[i]).min(max[i]));
+
+        cell_vertices[cell_index(cell)] =
+            Some(mesh.push_vertex(position, sdf.normal(position, epsilon)));
+    }
+
+    // Every edge of the grid crossed by the surface generates a quad joining the vertices of the
+    // four cells around it. Edges on the border of the grid do not have four cells around them.
+    for point in grid.points() {
+        let value = samples.value(point);
+
+        for axis in 0..3 {
+            let [b, c] = [(axis + 1) % 3, (axis + 2) % 3];
+
+            if point[axis] >= grid.resolution[axis]
+                || point[b] == 0
+                || point[c] == 0
+                || point[b] >= grid.resolution[b]
+                || point[c] >= grid.resolution[c]
+            {
+                continue;
+            }
+
+            let mut next = point;
+            next[axis] += 1;
+
+            let inside = value < Scalar::zero();
+            if inside == (samples.value(next) < Scalar::zero()) {
+                continue;
+            }
+
+            // The cells are ordered counter-clockwise around the axis of the edge.
+            let quad = [[1, 1], [0, 1], [0, 0], [1, 0]].map(|[db, dc]| {
+                let mut cell = point;
+                cell[b] -= db;
+                cell[c] -= dc;
+                cell_vertices[cell_index(cell)]
+            });
+
+            let [Some(v0), Some(v1), Some(v2), Some(v3)] = quad else {
+                continue;
+            };
+
+            // The surface faces towards the outside point of the edge.
+            if inside {
+                mesh.indices.push([v0, v1, v2]);
+                mesh.indices.push([v0, v2, v3]);
+            } else {
+                mesh.indices.push([v0, v2, v1]);
+                mesh.indices.push([v0, v3, v2]);
+            }
+        }
+    }
+
+    mesh
+}
+
+/// Finds the point closest to all of the tangent planes given as positions and normals, using the
+/// mass point of the positions to regularize the solution.
+fn solve_qef<Scalar: Float>(planes: &[([Scalar; 3], [Scalar; 3])]) -> [Scalar; 3] {
+    let count = Scalar::from(planes.len()).unwrap();
+    let mass_point: [Scalar; 3] = std::array::from_fn(|i| {
+        planes
+            .iter()
+            .fold(Scalar::zero(), |acc, (position, _)| acc + position[i])
+            / count
+    });
+
+    // Build the normal equations relative to the mass point: (AᵀA) x = Aᵀb.
+    let mut ata = [[Scalar::zero(); 3]; 3];
+    let mut atb = [Scalar::zero(); 3];
+
+    for (position, normal) in planes {
+        let offset = dot(normal, &sub(position, &mass_point));
+
+        for i in 0..3 {
+            for j in 0..3 {
+                ata[i][j] = ata[i][j] + normal[i] * normal[j];
+            }
+            atb[i] = atb[i] + normal[i] * offset;
+        }
+    }
+
+    let (eigenvalues, eigenvectors) = symmetric_eigen(ata);
+    let largest = eigenvalues
+        .iter()
+        .fold(Scalar::zero(), |acc, e| acc.max(e.abs()));
+    let threshold = largest * Scalar::from(EIGENVALUE_THRESHOLD).unwrap();
+
+    // Apply the truncated pseudo-inverse V Σ⁺ Vᵀ to Aᵀb.
+    let mut solution = mass_point;
+    for k in 0..3 {
+        if eigenvalues[k].abs() <= threshold {
+            continue;
+        }
+
+        let vector = [eigenvectors[0][k], eigenvectors[1][k], eigenvectors[2][k]];
+        let factor = dot(&vector, &atb) / eigenvalues[k];
+
+        for i in 0..3 {
+            solution[i] = solution[i] + vector[i] * factor;
+        }
+    }
+
+    solution
+}
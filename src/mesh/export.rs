@@ -0,0 +1,160 @@
+use std::io::{Error, ErrorKind, Write};
+
+use num::Float;
+
+use super::Mesh;
+use crate::{
+    SdfState,
+    math::{cross, normalize, sub, to_f32},
+    render::{Material, to_rgb8},
+};
+
+impl<Scalar: Float, State> Mesh<Scalar, State> {
+    /// Encodes the mesh as a Wavefront OBJ file, including the vertex normals.
+    pub fn write_obj(&self, mut writer: impl Write) -> std::io::Result<()> {
+        for position in &self.positions {
+            let [x, y, z] = position.map(to_f32);
+            writeln!(writer, "v {x} {y} {z}")?;
+        }
+
+        for normal in &self.normals {
+            let [x, y, z] = normal.map(to_f32);
+            writeln!(writer, "vn {x} {y} {z}")?;
+        }
+
+        // OBJ indices start at 1.
+        for triangle in &self.indices {
+            let [a, b, c] = triangle.map(|index| index + 1);
+            writeln!(writer, "f {a}//{a} {b}//{b} {c}//{c}")?;
+        }
+
+        writer.flush()
+    }
+
+    /// Encodes the mesh as a binary STL file. STL has no notion of shared vertices, so every
+    /// triangle is written with its own vertices and its geometric normal.
+    pub fn write_stl(&self, mut writer: impl Write) -> std::io::Result<()> {
+        let mut bytes = Vec::with_capacity(84 + self.indices.len() * 50);
+
+        let mut header = [0; 80];
+        let name = b"lightwalk";
+        header[..name.len()].copy_from_slice(name);
+
+        bytes.extend_from_slice(&header);
+        bytes.extend_from_slice(&(self.indices.len() as u32).to_le_bytes());
+
+        for triangle in &self.indices {
+            let (normal, vertices) = self.facet(triangle);
+
+            for vector in std::iter::once(normal).chain(vertices) {
+                for axis in vector {
+                    bytes.extend_from_slice(&to_f32(axis).to_le_bytes());
+                }
+            }
+
+            // Attribute byte count, unused.
+            bytes.extend_from_slice(&0_u16.to_le_bytes());
+        }
+
+        writer.write_all(&bytes)?;
+        writer.flush()
+    }
+
+    /// Encodes the mesh as an ASCII STL file. See [`Mesh::write_stl`].
+    pub fn write_ascii_stl(&self, mut writer: impl Write) -> std::io::Result<()> {
+        writeln!(writer, "solid lightwalk")?;
+
+        for triangle in &self.indices {
+            let (normal, vertices) = self.facet(triangle);
+
+            let [x, y, z] = normal.map(to_f32);
+            writeln!(writer, "facet normal {x} {y} {z}")?;
+            writeln!(writer, "  outer loop")?;
+
+            for vertex in vertices {
+                let [x, y, z] = vertex.map(to_f32);
+                writeln!(writer, "    vertex {x} {y} {z}")?;
+            }
+
+            writeln!(writer, "  endloop")?;
+            writeln!(writer, "endfacet")?;
+        }
+
+        writeln!(writer, "endsolid lightwalk")?;
+        writer.flush()
+    }
+
+    /// Encodes the mesh as an ASCII PLY file, including the vertex normals.
+    pub fn write_ply(&self, writer: impl Write) -> std::io::Result<()> {
+        self.write_ply_with(writer, None)
+    }
+
+    fn write_ply_with(
+        &self,
+        mut writer: impl Write,
+        colors: Option<&[[u8; 3]]>,
+    ) -> std::io::Result<()> {
+        writeln!(writer, "ply")?;
+        writeln!(writer, "format ascii 1.0")?;
+        writeln!(writer, "comment generated by lightwalk")?;
+        writeln!(writer, "element vertex {}", self.positions.len())?;
+        for property in ["x", "y", "z", "nx", "ny", "nz"] {
+            writeln!(writer, "property float {property}")?;
+        }
+        if colors.is_some() {
+            for property in ["red", "green", "blue"] {
+                writeln!(writer, "property uchar {property}")?;
+            }
+        }
+        writeln!(writer, "element face {}", self.indices.len())?;
+        writeln!(writer, "property list uchar uint vertex_indices")?;
+        writeln!(writer, "end_header")?;
+
+        for (i, (position, normal)) in self.positions.iter().zip(&self.normals).enumerate() {
+            let [x, y, z] = position.map(to_f32);
+            let [nx, ny, nz] = normal.map(to_f32);
+            write!(writer, "{x} {y} {z} {nx} {ny} {nz}")?;
+
+            if let Some(colors) = colors {
+                let [r, g, b] = colors[i];
+                write!(writer, " {r} {g} {b}")?;
+            }
+
+            writeln!(writer)?;
+        }
+
+        for [a, b, c] in &self.indices {
+            writeln!(writer, "3 {a} {b} {c}")?;
+        }
+
+        writer.flush()
+    }
+
+    /// Returns the geometric normal and the vertices of a triangle.
+    fn facet(&self, triangle: &[u32; 3]) -> ([Scalar; 3], [[Scalar; 3]; 3]) {
+        let vertices = triangle.map(|index| self.positions[index as usize]);
+        let normal = normalize(&cross(
+            &sub(&vertices[1], &vertices[0]),
+            &sub(&vertices[2], &vertices[0]),
+        ));
+
+        (normal, vertices)
+    }
+}
+
+impl<Scalar: Float, State: SdfState + Material<Scalar>> Mesh<Scalar, State> {
+    /// Encodes the mesh as an ASCII PLY file with per-vertex colors taken from the material of the
+    /// states of the vertices. The states must have been evaluated with
+    /// [`Mesh::with_states`](super::Mesh::with_states) beforehand.
+    pub fn write_colored_ply(&self, writer: impl Write) -> std::io::Result<()> {
+        let Some(states) = &self.states else {
+            return Err(Error::new(
+                ErrorKind::InvalidInput,
+                "Cannot write vertex colors of a mesh without states",
+            ));
+        };
+
+        let colors: Vec<_> = states.iter().map(|state| to_rgb8(state.color())).collect();
+        self.write_ply_with(writer, Some(&colors))
+    }
+}
@@ -0,0 +1,174 @@
+use std::{
+    collections::HashMap,
+    io::{BufRead, Error, ErrorKind, Read},
+};
+
+use num::Float;
+
+use super::Mesh;
+use crate::math::{add, cross, normalize, sub};
+
+impl<Scalar: Float> Mesh<Scalar> {
+    /// Decodes a Wavefront OBJ file. Only the vertex positions and faces are read: polygonal faces
+    /// are split into triangles and the normals are recomputed from the geometry.
+    pub fn read_obj(reader: impl BufRead) -> std::io::Result<Self> {
+        let mut mesh = Self::default();
+
+        for line in reader.lines() {
+            let line = line?;
+            let mut tokens = line.split_whitespace();
+
+            match tokens.next() {
+                Some("v") => {
+                    let mut position = [Scalar::zero(); 3];
+                    for axis in &mut position {
+                        *axis = parse_scalar(tokens.next())?;
+                    }
+                    mesh.positions.push(position);
+                }
+                Some("f") => {
+                    // Vertices are given as `v`, `v/vt`, `v//vn` or `v/vt/vn`, and negative
+                    // indices count from the last vertex.
+                    let face = tokens
+                        .map(|token| {
+                            let index: i64 = token
+                                .split('/')
+                                .next()
+                                .and_then(|index| index.parse().ok())
+                                .ok_or_else(|| invalid_data("Invalid OBJ face index"))?;
+
+                            let index = if index < 0 {
+                                mesh.positions.len() as i64 + index
+                            } else {
+                                index - 1
+                            };
+
+                            if index < 0 || index as usize >= mesh.positions.len() {
+                                return Err(invalid_data("Out of bounds OBJ face index"));
+                            }
+
+                            Ok(index as u32)
+                        })
+                        .collect::<std::io::Result<Vec<_>>>()?;
+
+                    for i in 1..face.len().saturating_sub(1) {
+                        mesh.indices.push([face[0], face[i], face[i + 1]]);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        mesh.recompute_normals();
+        Ok(mesh)
+    }
+
+    /// Decodes a binary or ASCII STL file. Identical vertices of neighbouring triangles are merged
+    /// so the mesh is connected, and the normals are recomputed from the geometry.
+    pub fn read_stl(mut reader: impl Read) -> std::io::Result<Self> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes)?;
+
+        // ASCII files may start with `solid` too, so the size of the file is used to tell both
+        // formats apart.
+        let is_binary = bytes.len() >= 84 && {
+            let count = u32::from_le_bytes([bytes[80], bytes[81], bytes[82], bytes[83]]);
+            bytes.len() == 84 + 50 * count as usize
+        };
+
+        let triangles = if is_binary {
+            bytes[84..]
+                .chunks_exact(50)
+                .map(|facet| {
+                    // Skip the normal, and read the three vertices.
+                    std::array::from_fn(|vertex| {
+                        std::array::from_fn(|axis| {
+                            let start = 12 + vertex * 12 + axis * 4;
+                            f32::from_le_bytes(facet[start..start + 4].try_into().unwrap())
+                        })
+                    })
+                })
+                .collect()
+        } else {
+            let text =
+                std::str::from_utf8(&bytes).map_err(|_| invalid_data("Invalid ASCII STL file"))?;
+
+            let vertices = text
+                .lines()
+                .filter_map(|line| line.trim_start().strip_prefix("vertex"))
+                .map(|line| {
+                    let mut tokens = line.split_whitespace();
+                    let mut vertex = [0.0; 3];
+                    for axis in &mut vertex {
+                        *axis = tokens
+                            .next()
+                            .and_then(|token| token.parse().ok())
+                            .ok_or_else(|| invalid_data("Invalid STL vertex"))?;
+                    }
+                    Ok(vertex)
+                })
+                .collect::<std::io::Result<Vec<[f32; 3]>>>()?;
+
+            if vertices.len() % 3 != 0 {
+                return Err(invalid_data("STL facets must have three vertices"));
+            }
+
+            vertices
+                .chunks_exact(3)
+                .map(|facet| [facet[0], facet[1], facet[2]])
+                .collect::<Vec<_>>()
+        };
+
+        let mut mesh = Self::default();
+        let mut welded: HashMap<[u32; 3], u32> = HashMap::new();
+
+        for triangle in triangles {
+            let triangle = triangle.map(|vertex: [f32; 3]| {
+                *welded.entry(vertex.map(f32::to_bits)).or_insert_with(|| {
+                    let index = mesh.positions.len() as u32;
+                    mesh.positions
+                        .push(vertex.map(|axis| Scalar::from(axis).unwrap()));
+                    index
+                })
+            });
+
+            mesh.indices.push(triangle);
+        }
+
+        mesh.recompute_normals();
+        Ok(mesh)
+    }
+}
+
+impl<Scalar: Float, State> Mesh<Scalar, State> {
+    /// Replaces the normals of the mesh with the area weighted average of the normals of the
+    /// triangles around each vertex.
+    pub fn recompute_normals(&mut self) {
+        let mut normals = vec![[Scalar::zero(); 3]; self.positions.len()];
+
+        for triangle in &self.indices {
+            let [a, b, c] = triangle.map(|i| self.positions[i as usize]);
+
+            // The length of the cross product is twice the area of the triangle.
+            let normal = cross(&sub(&b, &a), &sub(&c, &a));
+
+            for i in triangle {
+                normals[*i as usize] = add(&normals[*i as usize], &normal);
+            }
+        }
+
+        self.normals = normals.iter().map(normalize).collect();
+    }
+}
+
+fn parse_scalar<Scalar: Float>(token: Option<&str>) -> std::io::Result<Scalar> {
+    token
+        .and_then(|token| token.parse::<f64>().ok())
+        .and_then(Scalar::from)
+        .ok_or_else(|| invalid_data("Invalid OBJ vertex position"))
+}
+
+#[inline]
+fn invalid_data(message: &str) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
@@ -0,0 +1,65 @@
+use std::collections::HashMap;
+
+use num::Float;
+
+use super::{
+    Mesh, edge_crossing, normal_epsilon,
+    tables::{CORNERS, EDGES, TRIANGLES},
+};
+use crate::{Sdf, grid::Grid};
+
+pub(crate) fn marching_cubes<Scalar: Float, State>(
+    sdf: &impl Sdf<Scalar, 3, State>,
+    grid: &Grid<Scalar, 3>,
+) -> Mesh<Scalar> {
+    let samples = grid.sample(sdf);
+    let epsilon = normal_epsilon(grid);
+
+    let mut mesh = Mesh::default();
+
+    // Vertices lying on the same edge of the grid are shared between the neighbouring cells. An
+    // edge is identified by its grid point closest to the minimum and its axis.
+    let mut edge_vertices: HashMap<([usize; 3], usize), u32> = HashMap::new();
+
+    for cell in grid.cells() {
+        let corners = CORNERS.map(|offset| std::array::from_fn(|i| cell[i] + offset[i]));
+        let values = corners.map(|corner| samples.value(corner));
+
+        let configuration = values
+            .iter()
+            .enumerate()
+            .filter(|(_, value)| **value < Scalar::zero())
+            .fold(0, |acc, (i, _)| acc | (1 << i));
+
+        for triangle in TRIANGLES[configuration] {
+            let triangle = triangle.map(|edge| {
+                let [start, end] = EDGES[edge as usize];
+                let (start, end) = if corners[start] < corners[end] {
+                    (start, end)
+                } else {
+                    (end, start)
+                };
+
+                let axis = (0..3)
+                    .find(|&i| corners[start][i] != corners[end][i])
+                    .unwrap();
+
+                *edge_vertices
+                    .entry((corners[start], axis))
+                    .or_insert_with(|| {
+                        let position = edge_crossing(
+                            grid,
+                            (corners[start], values[start]),
+                            (corners[end], values[end]),
+                        );
+
+                        mesh.push_vertex(position, sdf.normal(position, epsilon))
+                    })
+            });
+
+            mesh.indices.push(triangle);
+        }
+    }
+
+    mesh
+}
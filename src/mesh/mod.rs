@@ -0,0 +1,139 @@
+mod dual_contouring;
+mod export;
+mod import;
+mod marching_cubes;
+mod tables;
+
+use num::Float;
+
+use crate::{Sdf, SdfState, grid::Grid};
+
+/// An indexed triangle mesh. Triangles are wound counter-clockwise when seen from outside of the
+/// surface.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mesh<Scalar: Float, State = ()> {
+    pub positions: Vec<[Scalar; 3]>,
+    pub normals: Vec<[Scalar; 3]>,
+    pub indices: Vec<[u32; 3]>,
+    /// The state of the SDF at each vertex, if it was requested with [`Mesh::with_states`].
+    pub states: Option<Vec<State>>,
+}
+
+impl<Scalar: Float, State> Default for Mesh<Scalar, State> {
+    fn default() -> Self {
+        Self {
+            positions: Vec::new(),
+            normals: Vec::new(),
+            indices: Vec::new(),
+            states: None,
+        }
+    }
+}
+
+impl<Scalar: Float, State> Mesh<Scalar, State> {
+    #[inline]
+    pub fn vertex_count(&self) -> usize {
+        self.positions.len()
+    }
+
+    #[inline]
+    pub fn triangle_count(&self) -> usize {
+        self.indices.len()
+    }
+
+    /// Evaluates the state of the SDF on every vertex of the mesh.
+    pub fn with_states<NewState: SdfState>(
+        self,
+        sdf: &impl Sdf<Scalar, 3, NewState>,
+    ) -> Mesh<Scalar, NewState> {
+        let states = self
+            .positions
+            .iter()
+            .map(|position| sdf.state(position))
+            .collect();
+
+        Mesh {
+            positions: self.positions,
+            normals: self.normals,
+            indices: self.indices,
+            states: Some(states),
+        }
+    }
+
+    /// Pushes a new vertex, returning its index.
+    pub(crate) fn push_vertex(&mut self, position: [Scalar; 3], normal: [Scalar; 3]) -> u32 {
+        let index = self.positions.len() as u32;
+
+        self.positions.push(position);
+        self.normals.push(normal);
+
+        index
+    }
+}
+
+/// Polygonizes the surface of a 3D SDF inside of a grid.
+pub trait SdfMeshOperations<Scalar: Float, State: SdfState>: Sdf<Scalar, 3, State> + Sized {
+    /// Extracts the surface of the SDF with the marching cubes algorithm. Vertices are placed on
+    /// the edges of the grid, which rounds off sharp features smaller than a cell.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use lightwalk::prelude::*;
+    ///
+    /// let grid = Grid::new([-1.5; 3], [1.5; 3], [32; 3]);
+    /// let mesh = Sphere.marching_cubes(&grid);
+    /// ```
+    #[inline]
+    fn marching_cubes(&self, grid: &Grid<Scalar, 3>) -> Mesh<Scalar> {
+        marching_cubes::marching_cubes(self, grid)
+    }
+
+    /// Extracts the surface of the SDF with the dual contouring algorithm. Each cell crossed by
+    /// the surface gets a single vertex placed using the normals of the SDF, which keeps the sharp
+    /// edges and corners of shapes like cubes, intersections and differences.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use lightwalk::prelude::*;
+    ///
+    /// let grid = Grid::new([-1.0; 3], [1.0; 3], [16; 3]);
+    /// let mesh = Cube.sub(Sphere.scale(0.6)).dual_contouring(&grid);
+    /// ```
+    #[inline]
+    fn dual_contouring(&self, grid: &Grid<Scalar, 3>) -> Mesh<Scalar> {
+        dual_contouring::dual_contouring(self, grid)
+    }
+}
+
+impl<T, Scalar: Float, State: SdfState> SdfMeshOperations<Scalar, State> for T where
+    T: Sdf<Scalar, 3, State>
+{
+}
+
+/// The epsilon used when computing the normals of the vertices of a mesh extracted from a grid.
+#[inline]
+fn normal_epsilon<Scalar: Float>(grid: &Grid<Scalar, 3>) -> Scalar {
+    let cell_size = grid.cell_size();
+    let smallest = cell_size[0].min(cell_size[1]).min(cell_size[2]);
+
+    smallest * Scalar::from(0.01).unwrap()
+}
+
+/// Returns the point where the surface crosses the edge between two grid points, interpolating
+/// linearly between the distances sampled at both ends. The distances must have opposite signs.
+#[inline]
+fn edge_crossing<Scalar: Float>(
+    grid: &Grid<Scalar, 3>,
+    (start, start_value): ([usize; 3], Scalar),
+    (end, end_value): ([usize; 3], Scalar),
+) -> [Scalar; 3] {
+    let t = start_value / (start_value - end_value);
+
+    let from = grid.point(start);
+    let to = grid.point(end);
+
+    std::array::from_fn(|i| from[i] + (to[i] - from[i]) * t)
+}
@@ -0,0 +1,308 @@
+//! Lookup tables of the marching cubes algorithm.
+//!
+//! Corners are numbered going around the bottom face (`z = 0`) counter-clockwise when seen from
+//! above, starting from the minimum corner and moving along the X axis first, then around the top
+//! face in the same order, so that corner `i + 4` is above corner `i`:
+//!
+//! ```text
+//!       7-------6
+//!      /|      /|
+//!     4-------5 |
+//!     | 3-----|-2
+//!     |/      |/
+//!     0-------1
+//! ```
+//!
+//! The triangle table is indexed by the set of corners inside of the surface, with corner `i`
+//! setting bit `i`. Triangles are wound counter-clockwise when seen from outside of the surface.
+//! On faces with two diagonally opposed inside corners, the inside corners are always kept
+//! separated, which keeps neighbouring cells consistent and the mesh watertight.
+
+/// The offset of each corner from the minimum corner of the cell.
+pub(crate) const CORNERS: [[usize; 3]; 8] = [
+    [0, 0, 0],
+    [1, 0, 0],
+    [1, 1, 0],
+    [0, 1, 0],
+    [0, 0, 1],
+    [1, 0, 1],
+    [1, 1, 1],
+    [0, 1, 1],
+];
+
+/// The two corners at the ends of each edge.
+pub(crate) const EDGES: [[usize; 2]; 12] = [
+    [0, 1],
+    [1, 2],
+    [2, 3],
+    [3, 0],
+    [4, 5],
+    [5, 6],
+    [6, 7],
+    [7, 4],
+    [0, 4],
+    [1, 5],
+    [2, 6],
+    [3, 7],
+];
+
+/// The triangles generated for each configuration, as triplets of edge indices.
+#[rustfmt::skip]
+pub(crate) const TRIANGLES: [&[[u8; 3]]; 256] = [
+    &[],
+    &[[0, 3, 8]],
+    &[[0, 9, 1]],
+    &[[1, 3, 8], [1, 8, 9]],
+    &[[1, 10, 2]],
+    &[[0, 3, 8], [1, 10, 2]],
+    &[[0, 9, 10], [0, 10, 2]],
+    &[[2, 3, 8], [2, 8, 9], [2, 9, 10]],
+    &[[2, 11, 3]],
+    &[[0, 2, 11], [0, 11, 8]],
+    &[[0, 9, 1], [2, 11, 3]],
+    &[[1, 2, 11], [1, 11, 8], [1, 8, 9]],
+    &[[1, 10, 11], [1, 11, 3]],
+    &[[0, 1, 10], [0, 10, 11], [0, 11, 8]],
+    &[[0, 9, 10], [0, 10, 11], [0, 11, 3]],
+    &[[8, 9, 10], [8, 10, 11]],
+    &[[4, 8, 7]],
+    &[[0, 3, 7], [0, 7, 4]],
+    &[[0, 9, 1], [4, 8, 7]],
+    &[[1, 3, 7], [1, 7, 4], [1, 4, 9]],
+    &[[1, 10, 2], [4, 8, 7]],
+    &[[0, 3, 7], [0, 7, 4], [1, 10, 2]],
+    &[[0, 9, 10], [0, 10, 2], [4, 8, 7]],
+    &[[2, 3, 7], [2, 7, 4], [2, 4, 9], [2, 9, 10]],
+    &[[2, 11, 3], [4, 8, 7]],
+    &[[0, 2, 11], [0, 11, 7], [0, 7, 4]],
+    &[[0, 9, 1], [2, 11, 3], [4, 8, 7]],
+    &[[1, 2, 11], [1, 11, 7], [1, 7, 4], [1, 4, 9]],
+    &[[1, 10, 11], [1, 11, 3], [4, 8, 7]],
+    &[[0, 1, 10], [0, 10, 11], [0, 11, 7], [0, 7, 4]],
+    &[[0, 9, 10], [0, 10, 11], [0, 11, 3], [4, 8, 7]],
+    &[[4, 9, 10], [4, 10, 11], [4, 11, 7]],
+    &[[4, 5, 9]],
+    &[[0, 3, 8], [4, 5, 9]],
+    &[[0, 4, 5], [0, 5, 1]],
+    &[[1, 3, 8], [1, 8, 4], [1, 4, 5]],
+    &[[1, 10, 2], [4, 5, 9]],
+    &[[0, 3, 8], [1, 10, 2], [4, 5, 9]],
+    &[[0, 4, 5], [0, 5, 10], [0, 10, 2]],
+    &[[2, 3, 8], [2, 8, 4], [2, 4, 5], [2, 5, 10]],
+    &[[2, 11, 3], [4, 5, 9]],
+    &[[0, 2, 11], [0, 11, 8], [4, 5, 9]],
+    &[[0, 4, 5], [0, 5, 1], [2, 11, 3]],
+    &[[1, 2, 11], [1, 11, 8], [1, 8, 4], [1, 4, 5]],
+    &[[1, 10, 11], [1, 11, 3], [4, 5, 9]],
+    &[[0, 1, 10], [0, 10, 11], [0, 11, 8], [4, 5, 9]],
+    &[[0, 4, 5], [0, 5, 10], [0, 10, 11], [0, 11, 3]],
+    &[[4, 5, 10], [4, 10, 11], [4, 11, 8]],
+    &[[5, 9, 8], [5, 8, 7]],
+    &[[0, 3, 7], [0, 7, 5], [0, 5, 9]],
+    &[[0, 8, 7], [0, 7, 5], [0, 5, 1]],
+    &[[1, 3, 7], [1, 7, 5]],
+    &[[1, 10, 2], [5, 9, 8], [5, 8, 7]],
+    &[[0, 3, 7], [0, 7, 5], [0, 5, 9], [1, 10, 2]],
+    &[[0, 8, 7], [0, 7, 5], [0, 5, 10], [0, 10, 2]],
+    &[[2, 3, 7], [2, 7, 5], [2, 5, 10]],
+    &[[2, 11, 3], [5, 9, 8], [5, 8, 7]],
+    &[[0, 2, 11], [0, 11, 7], [0, 7, 5], [0, 5, 9]],
+    &[[0, 8, 7], [0, 7, 5], [0, 5, 1], [2, 11, 3]],
+    &[[1, 2, 11], [1, 11, 7], [1, 7, 5]],
+    &[[1, 10, 11], [1, 11, 3], [5, 9, 8], [5, 8, 7]],
+    &[[0, 1, 10], [0, 10, 11], [0, 11, 7], [0, 7, 5], [0, 5, 9]],
+    &[[0, 8, 7], [0, 7, 5], [0, 5, 10], [0, 10, 11], [0, 11, 3]],
+    &[[5, 10, 11], [5, 11, 7]],
+    &[[5, 6, 10]],
+    &[[0, 3, 8], [5, 6, 10]],
+    &[[0, 9, 1], [5, 6, 10]],
+    &[[1, 3, 8], [1, 8, 9], [5, 6, 10]],
+    &[[1, 5, 6], [1, 6, 2]],
+    &[[0, 3, 8], [1, 5, 6], [1, 6, 2]],
+    &[[0, 9, 5], [0, 5, 6], [0, 6, 2]],
+    &[[2, 3, 8], [2, 8, 9], [2, 9, 5], [2, 5, 6]],
+    &[[2, 11, 3], [5, 6, 10]],
+    &[[0, 2, 11], [0, 11, 8], [5, 6, 10]],
+    &[[0, 9, 1], [2, 11, 3], [5, 6, 10]],
+    &[[1, 2, 11], [1, 11, 8], [1, 8, 9], [5, 6, 10]],
+    &[[1, 5, 6], [1, 6, 11], [1, 11, 3]],
+    &[[0, 1, 5], [0, 5, 6], [0, 6, 11], [0, 11, 8]],
+    &[[0, 9, 5], [0, 5, 6], [0, 6, 11], [0, 11, 3]],
+    &[[5, 6, 11], [5, 11, 8], [5, 8, 9]],
+    &[[4, 8, 7], [5, 6, 10]],
+    &[[0, 3, 7], [0, 7, 4], [5, 6, 10]],
+    &[[0, 9, 1], [4, 8, 7], [5, 6, 10]],
+    &[[1, 3, 7], [1, 7, 4], [1, 4, 9], [5, 6, 10]],
+    &[[1, 5, 6], [1, 6, 2], [4, 8, 7]],
+    &[[0, 3, 7], [0, 7, 4], [1, 5, 6], [1, 6, 2]],
+    &[[0, 9, 5], [0, 5, 6], [0, 6, 2], [4, 8, 7]],
+    &[[2, 3, 7], [2, 7, 4], [2, 4, 9], [2, 9, 5], [2, 5, 6]],
+    &[[2, 11, 3], [4, 8, 7], [5, 6, 10]],
+    &[[0, 2, 11], [0, 11, 7], [0, 7, 4], [5, 6, 10]],
+    &[[0, 9, 1], [2, 11, 3], [4, 8, 7], [5, 6, 10]],
+    &[[1, 2, 11], [1, 11, 7], [1, 7, 4], [1, 4, 9], [5, 6, 10]],
+    &[[1, 5, 6], [1, 6, 11], [1, 11, 3], [4, 8, 7]],
+    &[[0, 1, 5], [0, 5, 6], [0, 6, 11], [0, 11, 7], [0, 7, 4]],
+    &[[0, 9, 5], [0, 5, 6], [0, 6, 11], [0, 11, 3], [4, 8, 7]],
+    &[[4, 9, 5], [4, 5, 6], [4, 6, 11], [4, 11, 7]],
+    &[[4, 6, 10], [4, 10, 9]],
+    &[[0, 3, 8], [4, 6, 10], [4, 10, 9]],
+    &[[0, 4, 6], [0, 6, 10], [0, 10, 1]],
+    &[[1, 3, 8], [1, 8, 4], [1, 4, 6], [1, 6, 10]],
+    &[[1, 9, 4], [1, 4, 6], [1, 6, 2]],
+    &[[0, 3, 8], [1, 9, 4], [1, 4, 6], [1, 6, 2]],
+    &[[0, 4, 6], [0, 6, 2]],
+    &[[2, 3, 8], [2, 8, 4], [2, 4, 6]],
+    &[[2, 11, 3], [4, 6, 10], [4, 10, 9]],
+    &[[0, 2, 11], [0, 11, 8], [4, 6, 10], [4, 10, 9]],
+    &[[0, 4, 6], [0, 6, 10], [0, 10, 1], [2, 11, 3]],
+    &[[1, 2, 11], [1, 11, 8], [1, 8, 4], [1, 4, 6], [1, 6, 10]],
+    &[[1, 9, 4], [1, 4, 6], [1, 6, 11], [1, 11, 3]],
+    &[[0, 1, 9], [0, 9, 4], [0, 4, 6], [0, 6, 11], [0, 11, 8]],
+    &[[0, 4, 6], [0, 6, 11], [0, 11, 3]],
+    &[[4, 6, 11], [4, 11, 8]],
+    &[[6, 10, 9], [6, 9, 8], [6, 8, 7]],
+    &[[0, 3, 7], [0, 7, 6], [0, 6, 10], [0, 10, 9]],
+    &[[0, 8, 7], [0, 7, 6], [0, 6, 10], [0, 10, 1]],
+    &[[1, 3, 7], [1, 7, 6], [1, 6, 10]],
+    &[[1, 9, 8], [1, 8, 7], [1, 7, 6], [1, 6, 2]],
+    &[[0, 3, 7], [0, 7, 6], [0, 6, 2], [0, 2, 1], [0, 1, 9]],
+    &[[0, 8, 7], [0, 7, 6], [0, 6, 2]],
+    &[[2, 3, 7], [2, 7, 6]],
+    &[[2, 11, 3], [6, 10, 9], [6, 9, 8], [6, 8, 7]],
+    &[[0, 2, 11], [0, 11, 7], [0, 7, 6], [0, 6, 10], [0, 10, 9]],
+    &[[0, 8, 7], [0, 7, 6], [0, 6, 10], [0, 10, 1], [2, 11, 3]],
+    &[[1, 2, 11], [1, 11, 7], [1, 7, 6], [1, 6, 10]],
+    &[[1, 9, 8], [1, 8, 7], [1, 7, 6], [1, 6, 11], [1, 11, 3]],
+    &[[0, 1, 9], [6, 11, 7]],
+    &[[0, 8, 7], [0, 7, 6], [0, 6, 11], [0, 11, 3]],
+    &[[6, 11, 7]],
+    &[[6, 7, 11]],
+    &[[0, 3, 8], [6, 7, 11]],
+    &[[0, 9, 1], [6, 7, 11]],
+    &[[1, 3, 8], [1, 8, 9], [6, 7, 11]],
+    &[[1, 10, 2], [6, 7, 11]],
+    &[[0, 3, 8], [1, 10, 2], [6, 7, 11]],
+    &[[0, 9, 10], [0, 10, 2], [6, 7, 11]],
+    &[[2, 3, 8], [2, 8, 9], [2, 9, 10], [6, 7, 11]],
+    &[[2, 6, 7], [2, 7, 3]],
+    &[[0, 2, 6], [0, 6, 7], [0, 7, 8]],
+    &[[0, 9, 1], [2, 6, 7], [2, 7, 3]],
+    &[[1, 2, 6], [1, 6, 7], [1, 7, 8], [1, 8, 9]],
+    &[[1, 10, 6], [1, 6, 7], [1, 7, 3]],
+    &[[0, 1, 10], [0, 10, 6], [0, 6, 7], [0, 7, 8]],
+    &[[0, 9, 10], [0, 10, 6], [0, 6, 7], [0, 7, 3]],
+    &[[6, 7, 8], [6, 8, 9], [6, 9, 10]],
+    &[[4, 8, 11], [4, 11, 6]],
+    &[[0, 3, 11], [0, 11, 6], [0, 6, 4]],
+    &[[0, 9, 1], [4, 8, 11], [4, 11, 6]],
+    &[[1, 3, 11], [1, 11, 6], [1, 6, 4], [1, 4, 9]],
+    &[[1, 10, 2], [4, 8, 11], [4, 11, 6]],
+    &[[0, 3, 11], [0, 11, 6], [0, 6, 4], [1, 10, 2]],
+    &[[0, 9, 10], [0, 10, 2], [4, 8, 11], [4, 11, 6]],
+    &[[2, 3, 11], [2, 11, 6], [2, 6, 4], [2, 4, 9], [2, 9, 10]],
+    &[[2, 6, 4], [2, 4, 8], [2, 8, 3]],
+    &[[0, 2, 6], [0, 6, 4]],
+    &[[0, 9, 1], [2, 6, 4], [2, 4, 8], [2, 8, 3]],
+    &[[1, 2, 6], [1, 6, 4], [1, 4, 9]],
+    &[[1, 10, 6], [1, 6, 4], [1, 4, 8], [1, 8, 3]],
+    &[[0, 1, 10], [0, 10, 6], [0, 6, 4]],
+    &[[0, 9, 10], [0, 10, 6], [0, 6, 4], [0, 4, 8], [0, 8, 3]],
+    &[[4, 9, 10], [4, 10, 6]],
+    &[[4, 5, 9], [6, 7, 11]],
+    &[[0, 3, 8], [4, 5, 9], [6, 7, 11]],
+    &[[0, 4, 5], [0, 5, 1], [6, 7, 11]],
+    &[[1, 3, 8], [1, 8, 4], [1, 4, 5], [6, 7, 11]],
+    &[[1, 10, 2], [4, 5, 9], [6, 7, 11]],
+    &[[0, 3, 8], [1, 10, 2], [4, 5, 9], [6, 7, 11]],
+    &[[0, 4, 5], [0, 5, 10], [0, 10, 2], [6, 7, 11]],
+    &[[2, 3, 8], [2, 8, 4], [2, 4, 5], [2, 5, 10], [6, 7, 11]],
+    &[[2, 6, 7], [2, 7, 3], [4, 5, 9]],
+    &[[0, 2, 6], [0, 6, 7], [0, 7, 8], [4, 5, 9]],
+    &[[0, 4, 5], [0, 5, 1], [2, 6, 7], [2, 7, 3]],
+    &[[1, 2, 6], [1, 6, 7], [1, 7, 8], [1, 8, 4], [1, 4, 5]],
+    &[[1, 10, 6], [1, 6, 7], [1, 7, 3], [4, 5, 9]],
+    &[[0, 1, 10], [0, 10, 6], [0, 6, 7], [0, 7, 8], [4, 5, 9]],
+    &[[0, 4, 5], [0, 5, 10], [0, 10, 6], [0, 6, 7], [0, 7, 3]],
+    &[[4, 5, 10], [4, 10, 6], [4, 6, 7], [4, 7, 8]],
+    &[[5, 9, 8], [5, 8, 11], [5, 11, 6]],
+    &[[0, 3, 11], [0, 11, 6], [0, 6, 5], [0, 5, 9]],
+    &[[0, 8, 11], [0, 11, 6], [0, 6, 5], [0, 5, 1]],
+    &[[1, 3, 11], [1, 11, 6], [1, 6, 5]],
+    &[[1, 10, 2], [5, 9, 8], [5, 8, 11], [5, 11, 6]],
+    &[[0, 3, 11], [0, 11, 6], [0, 6, 5], [0, 5, 9], [1, 10, 2]],
+    &[[0, 8, 11], [0, 11, 6], [0, 6, 5], [0, 5, 10], [0, 10, 2]],
+    &[[2, 3, 11], [2, 11, 6], [2, 6, 5], [2, 5, 10]],
+    &[[2, 6, 5], [2, 5, 9], [2, 9, 8], [2, 8, 3]],
+    &[[0, 2, 6], [0, 6, 5], [0, 5, 9]],
+    &[[0, 8, 3], [0, 3, 2], [0, 2, 6], [0, 6, 5], [0, 5, 1]],
+    &[[1, 2, 6], [1, 6, 5]],
+    &[[1, 10, 6], [1, 6, 5], [1, 5, 9], [1, 9, 8], [1, 8, 3]],
+    &[[0, 1, 10], [0, 10, 6], [0, 6, 5], [0, 5, 9]],
+    &[[0, 8, 3], [5, 10, 6]],
+    &[[5, 10, 6]],
+    &[[5, 7, 11], [5, 11, 10]],
+    &[[0, 3, 8], [5, 7, 11], [5, 11, 10]],
+    &[[0, 9, 1], [5, 7, 11], [5, 11, 10]],
+    &[[1, 3, 8], [1, 8, 9], [5, 7, 11], [5, 11, 10]],
+    &[[1, 5, 7], [1, 7, 11], [1, 11, 2]],
+    &[[0, 3, 8], [1, 5, 7], [1, 7, 11], [1, 11, 2]],
+    &[[0, 9, 5], [0, 5, 7], [0, 7, 11], [0, 11, 2]],
+    &[[2, 3, 8], [2, 8, 9], [2, 9, 5], [2, 5, 7], [2, 7, 11]],
+    &[[2, 10, 5], [2, 5, 7], [2, 7, 3]],
+    &[[0, 2, 10], [0, 10, 5], [0, 5, 7], [0, 7, 8]],
+    &[[0, 9, 1], [2, 10, 5], [2, 5, 7], [2, 7, 3]],
+    &[[1, 2, 10], [1, 10, 5], [1, 5, 7], [1, 7, 8], [1, 8, 9]],
+    &[[1, 5, 7], [1, 7, 3]],
+    &[[0, 1, 5], [0, 5, 7], [0, 7, 8]],
+    &[[0, 9, 5], [0, 5, 7], [0, 7, 3]],
+    &[[5, 7, 8], [5, 8, 9]],
+    &[[4, 8, 11], [4, 11, 10], [4, 10, 5]],
+    &[[0, 3, 11], [0, 11, 10], [0, 10, 5], [0, 5, 4]],
+    &[[0, 9, 1], [4, 8, 11], [4, 11, 10], [4, 10, 5]],
+    &[[1, 3, 11], [1, 11, 10], [1, 10, 5], [1, 5, 4], [1, 4, 9]],
+    &[[1, 5, 4], [1, 4, 8], [1, 8, 11], [1, 11, 2]],
+    &[[0, 3, 11], [0, 11, 2], [0, 2, 1], [0, 1, 5], [0, 5, 4]],
+    &[[0, 9, 5], [0, 5, 4], [0, 4, 8], [0, 8, 11], [0, 11, 2]],
+    &[[2, 3, 11], [4, 9, 5]],
+    &[[2, 10, 5], [2, 5, 4], [2, 4, 8], [2, 8, 3]],
+    &[[0, 2, 10], [0, 10, 5], [0, 5, 4]],
+    &[[0, 9, 1], [2, 10, 5], [2, 5, 4], [2, 4, 8], [2, 8, 3]],
+    &[[1, 2, 10], [1, 10, 5], [1, 5, 4], [1, 4, 9]],
+    &[[1, 5, 4], [1, 4, 8], [1, 8, 3]],
+    &[[0, 1, 5], [0, 5, 4]],
+    &[[0, 9, 5], [0, 5, 4], [0, 4, 8], [0, 8, 3]],
+    &[[4, 9, 5]],
+    &[[4, 7, 11], [4, 11, 10], [4, 10, 9]],
+    &[[0, 3, 8], [4, 7, 11], [4, 11, 10], [4, 10, 9]],
+    &[[0, 4, 7], [0, 7, 11], [0, 11, 10], [0, 10, 1]],
+    &[[1, 3, 8], [1, 8, 4], [1, 4, 7], [1, 7, 11], [1, 11, 10]],
+    &[[1, 9, 4], [1, 4, 7], [1, 7, 11], [1, 11, 2]],
+    &[[0, 3, 8], [1, 9, 4], [1, 4, 7], [1, 7, 11], [1, 11, 2]],
+    &[[0, 4, 7], [0, 7, 11], [0, 11, 2]],
+    &[[2, 3, 8], [2, 8, 4], [2, 4, 7], [2, 7, 11]],
+    &[[2, 10, 9], [2, 9, 4], [2, 4, 7], [2, 7, 3]],
+    &[[0, 2, 10], [0, 10, 9], [0, 9, 4], [0, 4, 7], [0, 7, 8]],
+    &[[0, 4, 7], [0, 7, 3], [0, 3, 2], [0, 2, 10], [0, 10, 1]],
+    &[[1, 2, 10], [4, 7, 8]],
+    &[[1, 9, 4], [1, 4, 7], [1, 7, 3]],
+    &[[0, 1, 9], [0, 9, 4], [0, 4, 7], [0, 7, 8]],
+    &[[0, 4, 7], [0, 7, 3]],
+    &[[4, 7, 8]],
+    &[[8, 11, 10], [8, 10, 9]],
+    &[[0, 3, 11], [0, 11, 10], [0, 10, 9]],
+    &[[0, 8, 11], [0, 11, 10], [0, 10, 1]],
+    &[[1, 3, 11], [1, 11, 10]],
+    &[[1, 9, 8], [1, 8, 11], [1, 11, 2]],
+    &[[0, 3, 11], [0, 11, 2], [0, 2, 1], [0, 1, 9]],
+    &[[0, 8, 11], [0, 11, 2]],
+    &[[2, 3, 11]],
+    &[[2, 10, 9], [2, 9, 8], [2, 8, 3]],
+    &[[0, 2, 10], [0, 10, 9]],
+    &[[0, 8, 3], [0, 3, 2], [0, 2, 10], [0, 10, 1]],
+    &[[1, 2, 10]],
+    &[[1, 9, 8], [1, 8, 3]],
+    &[[0, 1, 9]],
+    &[[0, 8, 3]],
+    &[],
+];
@@ -1,5 +1,9 @@
 pub use crate::Sdf;
+pub use crate::contour::*;
+pub use crate::grid::*;
 pub use crate::marcher::*;
+pub use crate::mesh::*;
+pub use crate::render::*;
 pub use crate::sdf::combinators::*;
 pub use crate::sdf::primitives::*;
 pub use crate::sdf::state::*;
@@ -0,0 +1,215 @@
+use num::Float;
+
+use crate::{
+    marcher::Ray,
+    math::{add, cross, normalize, scale, sub},
+};
+
+/// The projection used by a [`Camera`] to turn pixels into rays.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Projection<Scalar: Float> {
+    /// All rays start at the position of the camera and spread out according to the vertical
+    /// field of view, given in radians.
+    Perspective { fov: Scalar },
+    /// All rays are parallel to the forward axis of the camera. The height is the size of the
+    /// visible area along the up axis, in world units.
+    Orthographic { height: Scalar },
+}
+
+/// A 3D camera generating one [`Ray`] per pixel of an image.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// let camera = Camera::look_at(
+///     [0.0, 0.0, -5.0],
+///     [0.0, 0.0, 0.0],
+///     [0.0, 1.0, 0.0],
+///     Projection::Perspective { fov: 60_f32.to_radians() },
+///     [640, 480],
+/// );
+///
+/// let ray = camera.ray([320, 240]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "CameraDescription<Scalar>",
+        into = "CameraDescription<Scalar>",
+        bound(
+            serialize = "Scalar: serde::Serialize",
+            deserialize = "Scalar: serde::Deserialize<'de>"
+        )
+    )
+)]
+pub struct Camera<Scalar: Float> {
+    pub position: [Scalar; 3],
+    pub projection: Projection<Scalar>,
+    /// The width and the height of the image, in pixels. Pixels are square, so the aspect ratio of
+    /// the visible area is the one of the resolution.
+    pub resolution: [usize; 2],
+
+    forward: [Scalar; 3],
+    right: [Scalar; 3],
+    up: [Scalar; 3],
+}
+
+/// The inputs of [`Camera::look_at`], which is all a camera is serialized as. The basis of the
+/// camera is rebuilt from them when deserializing.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct CameraDescription<Scalar: Float> {
+    position: [Scalar; 3],
+    target: [Scalar; 3],
+    up: [Scalar; 3],
+    projection: Projection<Scalar>,
+    resolution: [usize; 2],
+}
+
+#[cfg(feature = "serde")]
+impl<Scalar: Float> From<Camera<Scalar>> for CameraDescription<Scalar> {
+    #[inline]
+    fn from(camera: Camera<Scalar>) -> Self {
+        Self {
+            position: camera.position,
+            target: add(&camera.position, &camera.forward),
+            up: camera.up,
+            projection: camera.projection,
+            resolution: camera.resolution,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<Scalar: Float> TryFrom<CameraDescription<Scalar>> for Camera<Scalar> {
+    type Error = &'static str;
+
+    fn try_from(description: CameraDescription<Scalar>) -> Result<Self, Self::Error> {
+        let (forward, right, up) =
+            Camera::basis(&description.position, &description.target, &description.up).ok_or(
+                "Cannot define a camera whose up vector is parallel to its view direction",
+            )?;
+
+        Ok(Self {
+            position: description.position,
+            projection: description.projection,
+            resolution: description.resolution,
+            forward,
+            right,
+            up,
+        })
+    }
+}
+
+impl<Scalar: Float> Camera<Scalar> {
+    /// Creates a camera placed at `position` looking towards `target`. The `up` vector does not
+    /// need to be orthogonal to the view direction, but it must not be parallel to it.
+    pub fn look_at(
+        position: [Scalar; 3],
+        target: [Scalar; 3],
+        up: [Scalar; 3],
+        projection: Projection<Scalar>,
+        resolution: [usize; 2],
+    ) -> Self {
+        let Some((forward, right, up)) = Self::basis(&position, &target, &up) else {
+            panic!("Cannot define a camera whose up vector is parallel to its view direction");
+        };
+
+        Self {
+            position,
+            projection,
+            resolution,
+            forward,
+            right,
+            up,
+        }
+    }
+
+    /// Returns the forward, right and up axes of a camera placed at `position` looking towards
+    /// `target`, or `None` if the up vector is parallel to the view direction.
+    fn basis(
+        position: &[Scalar; 3],
+        target: &[Scalar; 3],
+        up: &[Scalar; 3],
+    ) -> Option<([Scalar; 3], [Scalar; 3], [Scalar; 3])> {
+        let forward = normalize(&sub(target, position));
+        let right = normalize(&cross(up, &forward));
+
+        if right.iter().all(|e| *e == Scalar::zero()) {
+            return None;
+        }
+
+        Some((forward, right, cross(&forward, &right)))
+    }
+
+    /// Returns the ratio between the width and the height of the image.
+    #[inline]
+    pub fn aspect_ratio(&self) -> Scalar {
+        Scalar::from(self.resolution[0]).unwrap() / Scalar::from(self.resolution[1]).unwrap()
+    }
+
+    #[inline]
+    pub fn forward(&self) -> [Scalar; 3] {
+        self.forward
+    }
+
+    #[inline]
+    pub fn right(&self) -> [Scalar; 3] {
+        self.right
+    }
+
+    #[inline]
+    pub fn up(&self) -> [Scalar; 3] {
+        self.up
+    }
+
+    /// Returns the ray going through a point of the image plane. Both coordinates range from -1
+    /// to 1, with `[-1, -1]` being the bottom left corner of the image and `[1, 1]` the top right.
+    pub fn ray_from_ndc(&self, ndc: [Scalar; 2]) -> Ray<Scalar, 3> {
+        let half_height = match self.projection {
+            Projection::Perspective { fov } => (fov / Scalar::from(2.0).unwrap()).tan(),
+            Projection::Orthographic { height } => height / Scalar::from(2.0).unwrap(),
+        };
+
+        let offset = add(
+            &scale(&self.right, ndc[0] * half_height * self.aspect_ratio()),
+            &scale(&self.up, ndc[1] * half_height),
+        );
+
+        match self.projection {
+            Projection::Perspective { .. } => Ray {
+                origin: self.position,
+                direction: normalize(&add(&self.forward, &offset)),
+            },
+            Projection::Orthographic { .. } => Ray {
+                origin: add(&self.position, &offset),
+                direction: self.forward,
+            },
+        }
+    }
+
+    /// Returns the ray going through the center of a given pixel. Pixels are indexed by column
+    /// then row, with `[0, 0]` being the top left corner of the image.
+    #[inline]
+    pub fn ray(&self, [x, y]: [usize; 2]) -> Ray<Scalar, 3> {
+        let half = Scalar::from(0.5).unwrap();
+        let two = Scalar::from(2.0).unwrap();
+
+        let u = (Scalar::from(x).unwrap() + half) / Scalar::from(self.resolution[0]).unwrap();
+        let v = (Scalar::from(y).unwrap() + half) / Scalar::from(self.resolution[1]).unwrap();
+
+        self.ray_from_ndc([u * two - Scalar::one(), Scalar::one() - v * two])
+    }
+
+    /// Iterates over the rays of every pixel of the image, row by row starting from the top.
+    pub fn rays(&self) -> impl Iterator<Item = ([usize; 2], Ray<Scalar, 3>)> + '_ {
+        let [width, height] = self.resolution;
+
+        (0..height).flat_map(move |y| (0..width).map(move |x| ([x, y], self.ray([x, y]))))
+    }
+}
@@ -0,0 +1,94 @@
+use std::io::Write;
+
+/// An in-memory image stored row by row, starting from the top left corner.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Framebuffer<Pixel> {
+    width: usize,
+    height: usize,
+    pixels: Vec<Pixel>,
+}
+
+impl<Pixel> Framebuffer<Pixel> {
+    /// Creates a framebuffer by calling the given function on the coordinates of every pixel, row
+    /// by row.
+    pub fn from_fn(
+        [width, height]: [usize; 2],
+        mut pixel: impl FnMut([usize; 2]) -> Pixel,
+    ) -> Self {
+        let pixels = (0..height)
+            .flat_map(|y| (0..width).map(move |x| [x, y]))
+            .map(&mut pixel)
+            .collect();
+
+        Self {
+            width,
+            height,
+            pixels,
+        }
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    pub fn pixels(&self) -> &[Pixel] {
+        &self.pixels
+    }
+
+    #[inline]
+    pub fn pixels_mut(&mut self) -> &mut [Pixel] {
+        &mut self.pixels
+    }
+
+    #[inline]
+    pub fn get(&self, [x, y]: [usize; 2]) -> Option<&Pixel> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.pixels.get(y * self.width + x)
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, [x, y]: [usize; 2]) -> Option<&mut Pixel> {
+        if x >= self.width || y >= self.height {
+            return None;
+        }
+
+        self.pixels.get_mut(y * self.width + x)
+    }
+
+    /// Transforms every pixel of the framebuffer, keeping its dimensions.
+    pub fn map<Other>(self, f: impl FnMut(Pixel) -> Other) -> Framebuffer<Other> {
+        Framebuffer {
+            width: self.width,
+            height: self.height,
+            pixels: self.pixels.into_iter().map(f).collect(),
+        }
+    }
+}
+
+impl<Pixel: Clone> Framebuffer<Pixel> {
+    pub fn new(resolution: [usize; 2], fill: Pixel) -> Self {
+        Self::from_fn(resolution, |_| fill.clone())
+    }
+}
+
+impl Framebuffer<[u8; 3]> {
+    /// Encodes the framebuffer as a binary PPM (P6) image.
+    pub fn write_ppm(&self, mut writer: impl Write) -> std::io::Result<()> {
+        write!(writer, "P6\n{} {}\n255\n", self.width, self.height)?;
+
+        let bytes: Vec<u8> = self.pixels.iter().flatten().copied().collect();
+        writer.write_all(&bytes)?;
+
+        writer.flush()
+    }
+}
@@ -0,0 +1,7 @@
+mod camera;
+mod framebuffer;
+mod shading;
+
+pub use camera::{Camera, Projection};
+pub use framebuffer::Framebuffer;
+pub use shading::{Light, Lighting, Material, to_rgb8};
@@ -0,0 +1,207 @@
+use num::Float;
+
+use crate::{
+    SdfState,
+    marcher::{Collision, Ray},
+    math::{dot, length, normalize, scale, sub},
+};
+
+/// Describes how the surface of an SDF reacts to light. This trait is meant to be implemented on
+/// the state of the SDFs that are rendered with a [`Lighting`].
+pub trait Material<Scalar: Float> {
+    /// The diffuse color of the surface, with each channel ranging from 0 to 1.
+    fn color(&self) -> [Scalar; 3];
+
+    /// The strength of the specular highlights.
+    #[inline]
+    fn specular(&self) -> Scalar {
+        Scalar::from(0.5).unwrap()
+    }
+
+    /// The Phong exponent of the specular highlights. Higher values make smaller and sharper
+    /// highlights.
+    #[inline]
+    fn shininess(&self) -> Scalar {
+        Scalar::from(32.0).unwrap()
+    }
+}
+
+/// SDFs without state are rendered as a light grey surface.
+impl<Scalar: Float> Material<Scalar> for () {
+    #[inline]
+    fn color(&self) -> [Scalar; 3] {
+        [Scalar::from(0.8).unwrap(); 3]
+    }
+}
+
+impl<Scalar: Float> Material<Scalar> for [Scalar; 3] {
+    #[inline]
+    fn color(&self) -> [Scalar; 3] {
+        *self
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Light<Scalar: Float> {
+    /// Lights every surface evenly, regardless of its orientation.
+    Ambient {
+        color: [Scalar; 3],
+        intensity: Scalar,
+    },
+    /// A light infinitely far away, such as the sun. The direction is the one the light travels
+    /// in, and does not need to be normalized.
+    Directional {
+        direction: [Scalar; 3],
+        color: [Scalar; 3],
+        intensity: Scalar,
+    },
+    /// A light emitted from a single point, whose intensity falls off with the square of the
+    /// distance.
+    Point {
+        position: [Scalar; 3],
+        color: [Scalar; 3],
+        intensity: Scalar,
+    },
+}
+
+impl<Scalar: Float> Light<Scalar> {
+    /// Returns the normalized direction from the point towards the light, its distance and its
+    /// incoming radiance at the point. Ambient lights have no direction.
+    pub fn incoming(&self, point: &[Scalar; 3]) -> (Option<[Scalar; 3]>, Scalar, [Scalar; 3]) {
+        match *self {
+            Light::Ambient { color, intensity } => {
+                (None, Scalar::infinity(), scale(&color, intensity))
+            }
+            Light::Directional {
+                direction,
+                color,
+                intensity,
+            } => (
+                Some(normalize(&scale(&direction, -Scalar::one()))),
+                Scalar::infinity(),
+                scale(&color, intensity),
+            ),
+            Light::Point {
+                position,
+                color,
+                intensity,
+            } => {
+                let offset = sub(&position, point);
+                let distance = length(&offset);
+
+                (
+                    Some(normalize(&offset)),
+                    distance,
+                    scale(&color, intensity / (distance * distance)),
+                )
+            }
+        }
+    }
+}
+
+/// Shades the hits of a [`Marcher`](crate::marcher::Marcher) using the Phong reflection model.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Lighting<Scalar: Float> {
+    pub lights: Vec<Light<Scalar>>,
+    /// The color returned for rays that do not hit anything.
+    pub background: [Scalar; 3],
+    /// When set, directional and point lights cast soft shadows with the given softness. See
+    /// [`Marcher::soft_shadow`](crate::marcher::Marcher::soft_shadow).
+    pub shadow_softness: Option<Scalar>,
+    /// When set, ambient lights are attenuated by ambient occlusion sampled with the given
+    /// spacing. See [`Marcher::ambient_occlusion`](crate::marcher::Marcher::ambient_occlusion).
+    pub ambient_occlusion: Option<Scalar>,
+}
+
+impl<Scalar: Float> Lighting<Scalar> {
+    #[inline]
+    pub fn new(lights: Vec<Light<Scalar>>, background: [Scalar; 3]) -> Self {
+        Self {
+            lights,
+            background,
+            shadow_softness: None,
+            ambient_occlusion: None,
+        }
+    }
+
+    /// Returns the linear color of a hit, or the background color if the ray missed. The channels
+    /// are not clamped.
+    pub fn shade<State: SdfState + Material<Scalar>>(
+        &self,
+        ray: &Ray<Scalar, 3>,
+        collision: Option<&Collision<Scalar, 3, State>>,
+    ) -> [Scalar; 3] {
+        let Some(collision) = collision else {
+            return self.background;
+        };
+
+        self.shade_with(ray, collision, |_| Scalar::one())
+    }
+
+    /// Same as [`Lighting::shade`], but the contribution of every light is multiplied by the
+    /// visibility factor returned by the closure. This is used to add shadows to directional and
+    /// point lights, and ambient occlusion to ambient lights.
+    pub fn shade_with<State: SdfState + Material<Scalar>>(
+        &self,
+        ray: &Ray<Scalar, 3>,
+        collision: &Collision<Scalar, 3, State>,
+        mut visibility: impl FnMut(&Light<Scalar>) -> Scalar,
+    ) -> [Scalar; 3] {
+        let material = &collision.state;
+        let albedo = material.color();
+        let normal = collision.normal;
+        let view = scale(&ray.direction, -Scalar::one());
+
+        let mut color = [Scalar::zero(); 3];
+
+        for light in &self.lights {
+            let (direction, _, radiance) = light.incoming(&collision.position);
+
+            let Some(direction) = direction else {
+                let visibility = visibility(light);
+                for (i, channel) in color.iter_mut().enumerate() {
+                    *channel = *channel + albedo[i] * radiance[i] * visibility;
+                }
+                continue;
+            };
+
+            let lambert = dot(&normal, &direction);
+            if lambert <= Scalar::zero() {
+                continue;
+            }
+
+            let visibility = visibility(light);
+            if visibility <= Scalar::zero() {
+                continue;
+            }
+
+            // Reflect the light direction around the normal for the Phong specular term.
+            let reflected = sub(
+                &scale(&normal, lambert * Scalar::from(2.0).unwrap()),
+                &direction,
+            );
+            let specular = dot(&reflected, &view)
+                .max(Scalar::zero())
+                .powf(material.shininess())
+                * material.specular();
+
+            for (i, channel) in color.iter_mut().enumerate() {
+                let diffuse = albedo[i] * lambert;
+                *channel = *channel + (diffuse + specular) * radiance[i] * visibility;
+            }
+        }
+
+        color
+    }
+}
+
+/// Converts a linear color to 8 bit channels, clamping values outside of the [0, 1] range.
+#[inline]
+pub fn to_rgb8<Scalar: Float>(color: [Scalar; 3]) -> [u8; 3] {
+    color.map(|channel| {
+        let channel = channel.max(Scalar::zero()).min(Scalar::one()) * Scalar::from(255.0).unwrap();
+        channel.round().to_u8().unwrap_or(0)
+    })
+}
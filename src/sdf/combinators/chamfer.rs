@@ -0,0 +1,152 @@
+use crate::{Sdf, SdfState};
+use num::Float;
+use std::marker::PhantomData;
+
+/// A union whose seam is cut by a 45 degree chamfer, as in the hg_sdf library. The radius is the
+/// distance from the seam to the edges of the chamfer along both surfaces.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChamferUnion<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    radius: Scalar,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for ChamferUnion<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        self.combine(lhs_distance, rhs_distance)
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.distance_and_state(*point).1
+    }
+
+    #[inline]
+    fn distance_and_state(&self, point: impl Into<[Scalar; DIM]>) -> (Scalar, State) {
+        let point = point.into();
+
+        let lhs_distance = self.lhs.distance_from_slice(&point);
+        let rhs_distance = self.rhs.distance_from_slice(&point);
+
+        let distance = self.combine(lhs_distance, rhs_distance);
+
+        if lhs_distance < rhs_distance {
+            (distance, self.lhs.state(&point))
+        } else {
+            (distance, self.rhs.state(&point))
+        }
+    }
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+    ChamferUnion<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(lhs: Lhs, rhs: Rhs, radius: Scalar) -> Self {
+        Self {
+            lhs,
+            rhs,
+            radius,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn combine(&self, lhs: Scalar, rhs: Scalar) -> Scalar {
+        let chamfer = (lhs + rhs - self.radius) * Scalar::from(0.5).unwrap().sqrt();
+
+        lhs.min(rhs).min(chamfer)
+    }
+}
+
+/// An intersection whose edges are cut by a 45 degree chamfer, as in the hg_sdf library.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChamferIntersection<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    radius: Scalar,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for ChamferIntersection<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        self.combine(lhs_distance, rhs_distance)
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.distance_and_state(*point).1
+    }
+
+    #[inline]
+    fn distance_and_state(&self, point: impl Into<[Scalar; DIM]>) -> (Scalar, State) {
+        let point = point.into();
+
+        let lhs_distance = self.lhs.distance_from_slice(&point);
+        let rhs_distance = self.rhs.distance_from_slice(&point);
+
+        let distance = self.combine(lhs_distance, rhs_distance);
+
+        if lhs_distance > rhs_distance {
+            (distance, self.lhs.state(&point))
+        } else {
+            (distance, self.rhs.state(&point))
+        }
+    }
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+    ChamferIntersection<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(lhs: Lhs, rhs: Rhs, radius: Scalar) -> Self {
+        Self {
+            lhs,
+            rhs,
+            radius,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn combine(&self, lhs: Scalar, rhs: Scalar) -> Scalar {
+        let chamfer = (lhs + rhs + self.radius) * Scalar::from(0.5).unwrap().sqrt();
+
+        lhs.max(rhs).max(chamfer)
+    }
+}
@@ -0,0 +1,111 @@
+use crate::{Sdf, SdfState};
+use num::Float;
+use std::marker::PhantomData;
+
+/// A union whose seam is filled by a row of round columns spanning the radius, as in the hg_sdf
+/// library.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ColumnsUnion<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    radius: Scalar,
+    columns: u32,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for ColumnsUnion<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        self.combine(lhs_distance, rhs_distance)
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.distance_and_state(*point).1
+    }
+
+    #[inline]
+    fn distance_and_state(&self, point: impl Into<[Scalar; DIM]>) -> (Scalar, State) {
+        let point = point.into();
+
+        let lhs_distance = self.lhs.distance_from_slice(&point);
+        let rhs_distance = self.rhs.distance_from_slice(&point);
+
+        let distance = self.combine(lhs_distance, rhs_distance);
+
+        if lhs_distance < rhs_distance {
+            (distance, self.lhs.state(&point))
+        } else {
+            (distance, self.rhs.state(&point))
+        }
+    }
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+    ColumnsUnion<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(lhs: Lhs, rhs: Rhs, radius: Scalar, columns: u32) -> Self {
+        if columns == 0 {
+            panic!("Cannot define a columns union without any column");
+        }
+
+        Self {
+            lhs,
+            rhs,
+            radius,
+            columns,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn combine(&self, lhs: Scalar, rhs: Scalar) -> Scalar {
+        let union = lhs.min(rhs);
+
+        // Far from the seam, the columns are hidden by the surfaces themselves.
+        if lhs >= self.radius || rhs >= self.radius {
+            return union;
+        }
+
+        let half = Scalar::from(0.5).unwrap();
+        let sqrt_2 = Scalar::from(2.0).unwrap().sqrt();
+
+        // hg_sdf produces n - 1 columns for a given n.
+        let n = self.columns + 1;
+        let column_radius = self.radius * sqrt_2 / (Scalar::from(2 * (n - 1)).unwrap() + sqrt_2);
+
+        // Rotate the plane of both distances by 45 degrees, so the seam is along the y axis.
+        let mut x =
+            (lhs + rhs) * half.sqrt() - sqrt_2 * half * self.radius + column_radius * sqrt_2;
+        let mut y = (rhs - lhs) * half.sqrt();
+
+        if n % 2 == 1 {
+            y = y + column_radius;
+        }
+
+        // Repeat the columns along the seam.
+        let size = column_radius + column_radius;
+        y = ((y + column_radius) % size + size) % size - column_radius;
+
+        x = x.min(x.hypot(y) - column_radius);
+
+        union.min(x)
+    }
+}
@@ -0,0 +1,155 @@
+use crate::{Sdf, SdfState};
+use num::Float;
+use std::marker::PhantomData;
+
+/// Cuts a carpenter style groove into the left hand side, along the surface of the right hand
+/// side, as in the hg_sdf library. The groove is as deep as the depth into the left hand side,
+/// and extends up to the width on both sides of the surface of the right hand side.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Groove<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    depth: Scalar,
+    width: Scalar,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for Groove<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        self.combine(lhs_distance, rhs_distance)
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.distance_and_state(*point).1
+    }
+
+    #[inline]
+    fn distance_and_state(&self, point: impl Into<[Scalar; DIM]>) -> (Scalar, State) {
+        let point = point.into();
+
+        let lhs_distance = self.lhs.distance_from_slice(&point);
+        let rhs_distance = self.rhs.distance_from_slice(&point);
+
+        let distance = self.combine(lhs_distance, rhs_distance);
+
+        if distance == lhs_distance {
+            (distance, self.lhs.state(&point))
+        } else {
+            (distance, self.rhs.state(&point))
+        }
+    }
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+    Groove<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(lhs: Lhs, rhs: Rhs, depth: Scalar, width: Scalar) -> Self {
+        Self {
+            lhs,
+            rhs,
+            depth,
+            width,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn combine(&self, lhs: Scalar, rhs: Scalar) -> Scalar {
+        lhs.max((lhs + self.depth).min(self.width - rhs.abs()))
+    }
+}
+
+/// Adds a carpenter style tongue to the left hand side, along the surface of the right hand side,
+/// as in the hg_sdf library. The tongue sticks out by the height from the left hand side, and
+/// extends up to the width on both sides of the surface of the right hand side.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tongue<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    height: Scalar,
+    width: Scalar,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for Tongue<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        self.combine(lhs_distance, rhs_distance)
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.distance_and_state(*point).1
+    }
+
+    #[inline]
+    fn distance_and_state(&self, point: impl Into<[Scalar; DIM]>) -> (Scalar, State) {
+        let point = point.into();
+
+        let lhs_distance = self.lhs.distance_from_slice(&point);
+        let rhs_distance = self.rhs.distance_from_slice(&point);
+
+        let distance = self.combine(lhs_distance, rhs_distance);
+
+        if distance == lhs_distance {
+            (distance, self.lhs.state(&point))
+        } else {
+            (distance, self.rhs.state(&point))
+        }
+    }
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+    Tongue<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(lhs: Lhs, rhs: Rhs, height: Scalar, width: Scalar) -> Self {
+        Self {
+            lhs,
+            rhs,
+            height,
+            width,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn combine(&self, lhs: Scalar, rhs: Scalar) -> Scalar {
+        lhs.min((lhs - self.height).max(rhs.abs() - self.width))
+    }
+}
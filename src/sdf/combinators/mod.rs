@@ -1,9 +1,23 @@
+mod chamfer;
+mod columns;
 mod difference;
+mod groove;
 mod intersection;
+mod morph;
 mod operations;
+mod smooth;
+mod stairs;
 mod union;
+mod xor;
 
+pub use chamfer::{ChamferIntersection, ChamferUnion};
+pub use columns::ColumnsUnion;
 pub use difference::Difference;
+pub use groove::{Groove, Tongue};
 pub use intersection::{Intersection, IterIntersection};
+pub use morph::Morph;
 pub use operations::{SdfCombinationOperations, SdfIterCombinationOperations};
+pub use smooth::{SmoothDifference, SmoothIntersection, SmoothKernel, SmoothUnion};
+pub use stairs::StairsUnion;
 pub use union::{IterUnion, Union};
+pub use xor::Xor;
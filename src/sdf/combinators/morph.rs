@@ -0,0 +1,100 @@
+use crate::{Sdf, SdfState};
+use num::Float;
+use std::marker::PhantomData;
+
+/// Linearly interpolates between two SDFs, going from the left hand side when `t` is 0 to the
+/// right hand side when `t` is 1. The result stays a valid distance bound as long as `t` is
+/// between 0 and 1. The state blender receives the distances and states of both SDFs, along with
+/// `t`, so that states can follow the morph.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// let mut morph = Sphere.morph(Cube, 0.0);
+///
+/// // Animate the sphere into a cube, the surface of the sphere moving in as the cube takes over.
+/// for frame in 0..=10 {
+///     morph.t = frame as f32 / 10.0;
+///
+///     assert!((morph.distance([1.0, 0.0, 0.0]) - 0.5 * morph.t).abs() < 1e-6);
+/// }
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Morph<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    pub t: Scalar,
+    state_blender: B,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B> Sdf<Scalar, DIM, State>
+    for Morph<Scalar, Lhs, Rhs, DIM, State, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        lhs_distance + (rhs_distance - lhs_distance) * self.t
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        let lhs_state = self.lhs.state(point);
+        let rhs_state = self.rhs.state(point);
+
+        (self.state_blender)((lhs_distance, lhs_state), (rhs_distance, rhs_state), self.t)
+    }
+
+    #[inline]
+    fn distance_and_state(&self, point: impl Into<[Scalar; DIM]>) -> (Scalar, State) {
+        let point = point.into();
+
+        let lhs_distance = self.lhs.distance_from_slice(&point);
+        let rhs_distance = self.rhs.distance_from_slice(&point);
+
+        let lhs_state = self.lhs.state(&point);
+        let rhs_state = self.rhs.state(&point);
+
+        let distance = lhs_distance + (rhs_distance - lhs_distance) * self.t;
+        let state =
+            (self.state_blender)((lhs_distance, lhs_state), (rhs_distance, rhs_state), self.t);
+
+        (distance, state)
+    }
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B>
+    Morph<Scalar, Lhs, Rhs, DIM, State, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    #[inline]
+    pub fn new(lhs: Lhs, rhs: Rhs, t: Scalar, state_blender: B) -> Self {
+        Self {
+            lhs,
+            rhs,
+            t,
+            state_blender,
+            _marker: PhantomData,
+        }
+    }
+}
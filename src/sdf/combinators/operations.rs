@@ -1,7 +1,11 @@
 use crate::{Sdf, SdfState};
 use num::Float;
 
-use super::{Difference, Intersection, Union, intersection::IterIntersection, union::IterUnion};
+use super::{
+    ChamferIntersection, ChamferUnion, ColumnsUnion, Difference, Groove, Intersection, Morph,
+    SmoothDifference, SmoothIntersection, SmoothKernel, SmoothUnion, StairsUnion, Tongue, Union,
+    Xor, intersection::IterIntersection, union::IterUnion,
+};
 
 fn closest_state_blender<Scalar: Float, State: SdfState>(
     (lhs_distance, lhs_state): (Scalar, State),
@@ -14,6 +18,29 @@ fn closest_state_blender<Scalar: Float, State: SdfState>(
     }
 }
 
+fn closest_surface_state_blender<Scalar: Float, State: SdfState>(
+    (lhs_distance, lhs_state): (Scalar, State),
+    (rhs_distance, rhs_state): (Scalar, State),
+) -> State {
+    if lhs_distance.abs() < rhs_distance.abs() {
+        lhs_state
+    } else {
+        rhs_state
+    }
+}
+
+fn weighted_state_blender<Scalar: Float, State: SdfState>(
+    (_, lhs_state): (Scalar, State),
+    (_, rhs_state): (Scalar, State),
+    weight: Scalar,
+) -> State {
+    if weight < Scalar::from(0.5).unwrap() {
+        lhs_state
+    } else {
+        rhs_state
+    }
+}
+
 pub trait SdfCombinationOperations<Scalar: Float, Rhs, const DIM: usize, State: SdfState>:
     Sdf<Scalar, DIM, State> + Sized
 where
@@ -38,6 +65,232 @@ where
     fn sub(self, rhs: Rhs) -> Difference<Scalar, Self, Rhs, DIM, State> {
         Difference::new(self, rhs)
     }
+
+    /// Blends both SDFs together over the blend radius. The state is taken from the SDF with the
+    /// largest weight in the blend; use [`smooth_add_with`](Self::smooth_add_with) to mix states
+    /// across the seam.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn smooth_add(
+        self,
+        rhs: Rhs,
+        radius: Scalar,
+        kernel: SmoothKernel,
+    ) -> SmoothUnion<
+        Scalar,
+        Self,
+        Rhs,
+        DIM,
+        State,
+        impl Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+    > {
+        SmoothUnion::new(self, rhs, radius, kernel, weighted_state_blender)
+    }
+
+    /// Blends both SDFs together over the blend radius like [`smooth_add`](Self::smooth_add),
+    /// building the state with a blender that receives the distances and states of both SDFs
+    /// along with the weight of the right hand side in the blend.
+    ///
+    /// # Example:
+    ///
+    /// ```rust
+    /// use lightwalk::prelude::*;
+    ///
+    /// let red = Sphere.translate(&[-0.8, 0.0, 0.0]).bind([1.0, 0.0, 0.0]);
+    /// let blue = Sphere.translate(&[0.8, 0.0, 0.0]).bind([0.0, 0.0, 1.0]);
+    ///
+    /// // Colors fade from red to blue across the seam.
+    /// let blob = red.smooth_add_with(
+    ///     blue,
+    ///     0.25,
+    ///     SmoothKernel::Polynomial,
+    ///     |(_, lhs): (f32, [f32; 3]), (_, rhs): (f32, [f32; 3]), weight| {
+    ///         std::array::from_fn(|i| lhs[i] + (rhs[i] - lhs[i]) * weight)
+    ///     },
+    /// );
+    ///
+    /// assert_eq!(blob.state(&[0.0, 0.0, 0.0]), [0.5, 0.0, 0.5]);
+    /// ```
+    #[inline]
+    fn smooth_add_with<B>(
+        self,
+        rhs: Rhs,
+        radius: Scalar,
+        kernel: SmoothKernel,
+        state_blender: B,
+    ) -> SmoothUnion<Scalar, Self, Rhs, DIM, State, B>
+    where
+        B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+    {
+        SmoothUnion::new(self, rhs, radius, kernel, state_blender)
+    }
+
+    /// Intersects both SDFs, rounding the edges of the intersection over the blend radius. The
+    /// state is taken from the SDF with the largest weight in the blend; use
+    /// [`smooth_mul_with`](Self::smooth_mul_with) to mix states across the edges.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn smooth_mul(
+        self,
+        rhs: Rhs,
+        radius: Scalar,
+        kernel: SmoothKernel,
+    ) -> SmoothIntersection<
+        Scalar,
+        Self,
+        Rhs,
+        DIM,
+        State,
+        impl Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+    > {
+        SmoothIntersection::new(self, rhs, radius, kernel, weighted_state_blender)
+    }
+
+    /// Intersects both SDFs like [`smooth_mul`](Self::smooth_mul), building the state with a
+    /// blender like the one of [`smooth_add_with`](Self::smooth_add_with).
+    #[inline]
+    fn smooth_mul_with<B>(
+        self,
+        rhs: Rhs,
+        radius: Scalar,
+        kernel: SmoothKernel,
+        state_blender: B,
+    ) -> SmoothIntersection<Scalar, Self, Rhs, DIM, State, B>
+    where
+        B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+    {
+        SmoothIntersection::new(self, rhs, radius, kernel, state_blender)
+    }
+
+    /// Carves the right hand side out of the left one, rounding the carved edges over the blend
+    /// radius. The state is taken from the SDF with the largest weight in the blend; use
+    /// [`smooth_sub_with`](Self::smooth_sub_with) to mix states across the carved edges.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn smooth_sub(
+        self,
+        rhs: Rhs,
+        radius: Scalar,
+        kernel: SmoothKernel,
+    ) -> SmoothDifference<
+        Scalar,
+        Self,
+        Rhs,
+        DIM,
+        State,
+        impl Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+    > {
+        SmoothDifference::new(self, rhs, radius, kernel, weighted_state_blender)
+    }
+
+    /// Carves the right hand side out of the left one like [`smooth_sub`](Self::smooth_sub),
+    /// building the state with a blender like the one of
+    /// [`smooth_add_with`](Self::smooth_add_with), the weight being the one of the carved out right
+    /// hand side.
+    #[inline]
+    fn smooth_sub_with<B>(
+        self,
+        rhs: Rhs,
+        radius: Scalar,
+        kernel: SmoothKernel,
+        state_blender: B,
+    ) -> SmoothDifference<Scalar, Self, Rhs, DIM, State, B>
+    where
+        B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+    {
+        SmoothDifference::new(self, rhs, radius, kernel, state_blender)
+    }
+
+    /// Keeps the parts of both SDFs that are inside of exactly one of them. The state is taken from
+    /// the SDF whose surface is the closest.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn xor(
+        self,
+        rhs: Rhs,
+    ) -> Xor<Scalar, Self, Rhs, DIM, State, impl Fn((Scalar, State), (Scalar, State)) -> State>
+    {
+        Xor::new(self, rhs, closest_surface_state_blender)
+    }
+
+    /// Linearly interpolates from this SDF to the right hand side by `t`. The state is taken from
+    /// the closest SDF; use [`Morph::new`] to blend states along the morph.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn morph(
+        self,
+        rhs: Rhs,
+        t: Scalar,
+    ) -> Morph<
+        Scalar,
+        Self,
+        Rhs,
+        DIM,
+        State,
+        impl Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+    > {
+        Morph::new(self, rhs, t, |lhs, rhs, _| closest_state_blender(lhs, rhs))
+    }
+
+    /// Unites both SDFs, cutting their seam with a 45 degree chamfer.
+    #[inline]
+    fn chamfer_add(self, rhs: Rhs, radius: Scalar) -> ChamferUnion<Scalar, Self, Rhs, DIM, State> {
+        ChamferUnion::new(self, rhs, radius)
+    }
+
+    /// Intersects both SDFs, cutting the edges of the intersection with a 45 degree chamfer.
+    #[inline]
+    fn chamfer_mul(
+        self,
+        rhs: Rhs,
+        radius: Scalar,
+    ) -> ChamferIntersection<Scalar, Self, Rhs, DIM, State> {
+        ChamferIntersection::new(self, rhs, radius)
+    }
+
+    /// Unites both SDFs, filling their seam with a staircase of the given number of steps.
+    #[inline]
+    fn stairs_add(
+        self,
+        rhs: Rhs,
+        radius: Scalar,
+        steps: u32,
+    ) -> StairsUnion<Scalar, Self, Rhs, DIM, State> {
+        StairsUnion::new(self, rhs, radius, steps)
+    }
+
+    /// Unites both SDFs, filling their seam with the given number of round columns.
+    #[inline]
+    fn columns_add(
+        self,
+        rhs: Rhs,
+        radius: Scalar,
+        columns: u32,
+    ) -> ColumnsUnion<Scalar, Self, Rhs, DIM, State> {
+        ColumnsUnion::new(self, rhs, radius, columns)
+    }
+
+    /// Cuts a groove into this SDF along the surface of the right hand side.
+    #[inline]
+    fn groove(
+        self,
+        rhs: Rhs,
+        depth: Scalar,
+        width: Scalar,
+    ) -> Groove<Scalar, Self, Rhs, DIM, State> {
+        Groove::new(self, rhs, depth, width)
+    }
+
+    /// Adds a tongue to this SDF along the surface of the right hand side.
+    #[inline]
+    fn tongue(
+        self,
+        rhs: Rhs,
+        height: Scalar,
+        width: Scalar,
+    ) -> Tongue<Scalar, Self, Rhs, DIM, State> {
+        Tongue::new(self, rhs, height, width)
+    }
 }
 
 impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
@@ -0,0 +1,370 @@
+use crate::{Sdf, SdfState};
+use num::Float;
+use std::marker::PhantomData;
+
+/// The smooth minimum used to blend two SDFs together, see "Smooth Minimum" by Inigo Quilez.
+/// Every kernel is normalized so that the blended distance is the minimum minus the blend radius
+/// where both distances are equal.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// let blob = Sphere.smooth_add(Sphere.translate(&[3.0, 0.0, 0.0]), 0.1, SmoothKernel::Exponential);
+///
+/// // Far away from both spheres, the blend is the distance to the closest one.
+/// assert!((blob.distance([100.0f32, 0.0, 0.0]) - 96.0).abs() < 1e-3);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SmoothKernel {
+    /// A quadratic polynomial, which only affects points whose distances differ by less than four
+    /// times the blend radius.
+    #[default]
+    Polynomial,
+    /// An exponential, which is infinitely smooth but affects the whole field.
+    Exponential,
+    /// A circular arc, which matches the rounded fillet between two planes.
+    Circular,
+}
+
+impl SmoothKernel {
+    /// Returns the smooth minimum of two distances, along with the weight of the second distance
+    /// in the blend, going from 0 where only the first one matters to 1 where only the second one
+    /// does.
+    pub fn smooth_min<Scalar: Float>(
+        self,
+        lhs: Scalar,
+        rhs: Scalar,
+        radius: Scalar,
+    ) -> (Scalar, Scalar) {
+        let zero = Scalar::zero();
+        let one = Scalar::one();
+        let half = Scalar::from(0.5).unwrap();
+
+        // The weights of the polynomial kernels are the derivative of the blended distance with
+        // respect to the distance furthest away, mirrored when the second one is the closest.
+        let mirror = |(distance, weight): (Scalar, Scalar)| {
+            if lhs < rhs {
+                (distance, weight)
+            } else {
+                (distance, one - weight)
+            }
+        };
+
+        match self {
+            Self::Polynomial => {
+                let radius = radius * Scalar::from(4.0).unwrap();
+                let h = (radius - (lhs - rhs).abs()).max(zero) / radius;
+
+                mirror((
+                    lhs.min(rhs) - h * h * radius * Scalar::from(0.25).unwrap(),
+                    h * half,
+                ))
+            }
+            Self::Exponential => {
+                // Shift both exponents by the closest distance, so that the largest term is 1 and
+                // neither underflows far from both SDFs nor overflows deep inside them.
+                let closest = lhs.min(rhs);
+                let lhs_weight = ((closest - lhs) / radius).exp2();
+                let rhs_weight = ((closest - rhs) / radius).exp2();
+                let sum = lhs_weight + rhs_weight;
+
+                (closest - radius * sum.log2(), rhs_weight / sum)
+            }
+            Self::Circular => {
+                let radius = radius / (one - half.sqrt());
+                let h = (radius - (lhs - rhs).abs()).max(zero) / radius;
+                let root = (one - h * (h - Scalar::from(2.0).unwrap())).sqrt();
+
+                mirror((
+                    lhs.min(rhs) - radius * half * (one + h - root),
+                    half * (one - (one - h) / root),
+                ))
+            }
+        }
+    }
+}
+
+#[inline]
+fn check_radius<Scalar: Float>(radius: Scalar) {
+    if radius <= Scalar::zero() {
+        panic!("Cannot define a smooth combination with a non positive blend radius");
+    }
+}
+
+/// A union whose seam is rounded over the blend radius. The state blender receives the distances
+/// and states of both SDFs, along with the weight of the right hand side in the blend, so that
+/// states can be mixed across the seam.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// let red = Sphere.translate(&[-0.8, 0.0, 0.0]).bind([1.0, 0.0, 0.0]);
+/// let blue = Sphere.translate(&[0.8, 0.0, 0.0]).bind([0.0, 0.0, 1.0]);
+///
+/// // Colors fade from red to blue across the seam.
+/// let blob = SmoothUnion::new(
+///     red,
+///     blue,
+///     0.25,
+///     SmoothKernel::Polynomial,
+///     |(_, lhs): (f32, [f32; 3]), (_, rhs): (f32, [f32; 3]), weight: f32| {
+///         std::array::from_fn(|i| lhs[i] + (rhs[i] - lhs[i]) * weight)
+///     },
+/// );
+///
+/// assert_eq!(blob.state(&[0.0, 0.0, 0.0]), [0.5, 0.0, 0.5]);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmoothUnion<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    radius: Scalar,
+    kernel: SmoothKernel,
+    state_blender: B,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B> Sdf<Scalar, DIM, State>
+    for SmoothUnion<Scalar, Lhs, Rhs, DIM, State, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        self.kernel
+            .smooth_min(lhs_distance, rhs_distance, self.radius)
+            .0
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.distance_and_state(*point).1
+    }
+
+    #[inline]
+    fn distance_and_state(&self, point: impl Into<[Scalar; DIM]>) -> (Scalar, State) {
+        let point = point.into();
+
+        let lhs_distance = self.lhs.distance_from_slice(&point);
+        let rhs_distance = self.rhs.distance_from_slice(&point);
+
+        let (distance, weight) = self
+            .kernel
+            .smooth_min(lhs_distance, rhs_distance, self.radius);
+
+        let state = (self.state_blender)(
+            (lhs_distance, self.lhs.state(&point)),
+            (rhs_distance, self.rhs.state(&point)),
+            weight,
+        );
+
+        (distance, state)
+    }
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B>
+    SmoothUnion<Scalar, Lhs, Rhs, DIM, State, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    #[inline]
+    pub fn new(lhs: Lhs, rhs: Rhs, radius: Scalar, kernel: SmoothKernel, state_blender: B) -> Self {
+        check_radius(radius);
+
+        Self {
+            lhs,
+            rhs,
+            radius,
+            kernel,
+            state_blender,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// An intersection whose edges are rounded over the blend radius. The state blender receives the
+/// same arguments as the one of [`SmoothUnion`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmoothIntersection<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    radius: Scalar,
+    kernel: SmoothKernel,
+    state_blender: B,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B> Sdf<Scalar, DIM, State>
+    for SmoothIntersection<Scalar, Lhs, Rhs, DIM, State, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        -self
+            .kernel
+            .smooth_min(-lhs_distance, -rhs_distance, self.radius)
+            .0
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.distance_and_state(*point).1
+    }
+
+    #[inline]
+    fn distance_and_state(&self, point: impl Into<[Scalar; DIM]>) -> (Scalar, State) {
+        let point = point.into();
+
+        let lhs_distance = self.lhs.distance_from_slice(&point);
+        let rhs_distance = self.rhs.distance_from_slice(&point);
+
+        let (distance, weight) = self
+            .kernel
+            .smooth_min(-lhs_distance, -rhs_distance, self.radius);
+
+        let state = (self.state_blender)(
+            (lhs_distance, self.lhs.state(&point)),
+            (rhs_distance, self.rhs.state(&point)),
+            weight,
+        );
+
+        (-distance, state)
+    }
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B>
+    SmoothIntersection<Scalar, Lhs, Rhs, DIM, State, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    #[inline]
+    pub fn new(lhs: Lhs, rhs: Rhs, radius: Scalar, kernel: SmoothKernel, state_blender: B) -> Self {
+        check_radius(radius);
+
+        Self {
+            lhs,
+            rhs,
+            radius,
+            kernel,
+            state_blender,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// A difference whose carved edges are rounded over the blend radius. The state blender receives
+/// the same arguments as the one of [`SmoothUnion`], the weight being the one of the carved out
+/// right hand side.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SmoothDifference<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    radius: Scalar,
+    kernel: SmoothKernel,
+    state_blender: B,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B> Sdf<Scalar, DIM, State>
+    for SmoothDifference<Scalar, Lhs, Rhs, DIM, State, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        -self
+            .kernel
+            .smooth_min(-lhs_distance, rhs_distance, self.radius)
+            .0
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.distance_and_state(*point).1
+    }
+
+    #[inline]
+    fn distance_and_state(&self, point: impl Into<[Scalar; DIM]>) -> (Scalar, State) {
+        let point = point.into();
+
+        let lhs_distance = self.lhs.distance_from_slice(&point);
+        let rhs_distance = self.rhs.distance_from_slice(&point);
+
+        let (distance, weight) = self
+            .kernel
+            .smooth_min(-lhs_distance, rhs_distance, self.radius);
+
+        let state = (self.state_blender)(
+            (lhs_distance, self.lhs.state(&point)),
+            (rhs_distance, self.rhs.state(&point)),
+            weight,
+        );
+
+        (-distance, state)
+    }
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B>
+    SmoothDifference<Scalar, Lhs, Rhs, DIM, State, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State), Scalar) -> State,
+{
+    #[inline]
+    pub fn new(lhs: Lhs, rhs: Rhs, radius: Scalar, kernel: SmoothKernel, state_blender: B) -> Self {
+        check_radius(radius);
+
+        Self {
+            lhs,
+            rhs,
+            radius,
+            kernel,
+            state_blender,
+            _marker: PhantomData,
+        }
+    }
+}
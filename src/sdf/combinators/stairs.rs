@@ -0,0 +1,87 @@
+use crate::{Sdf, SdfState};
+use num::Float;
+use std::marker::PhantomData;
+
+/// A union whose seam is filled by a staircase spanning the radius, as in the hg_sdf library.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StairsUnion<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    radius: Scalar,
+    steps: u32,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for StairsUnion<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        self.combine(lhs_distance, rhs_distance)
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.distance_and_state(*point).1
+    }
+
+    #[inline]
+    fn distance_and_state(&self, point: impl Into<[Scalar; DIM]>) -> (Scalar, State) {
+        let point = point.into();
+
+        let lhs_distance = self.lhs.distance_from_slice(&point);
+        let rhs_distance = self.rhs.distance_from_slice(&point);
+
+        let distance = self.combine(lhs_distance, rhs_distance);
+
+        if lhs_distance < rhs_distance {
+            (distance, self.lhs.state(&point))
+        } else {
+            (distance, self.rhs.state(&point))
+        }
+    }
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState>
+    StairsUnion<Scalar, Lhs, Rhs, DIM, State>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(lhs: Lhs, rhs: Rhs, radius: Scalar, steps: u32) -> Self {
+        if steps == 0 {
+            panic!("Cannot define a stairs union without any step");
+        }
+
+        Self {
+            lhs,
+            rhs,
+            radius,
+            steps,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn combine(&self, lhs: Scalar, rhs: Scalar) -> Scalar {
+        // hg_sdf produces n - 1 steps for a given n.
+        let step = self.radius / Scalar::from(self.steps + 1).unwrap();
+        let offset = rhs - self.radius;
+        let stairs = ((offset - lhs + step) % (step + step) + step + step) % (step + step) - step;
+
+        lhs.min(rhs)
+            .min(Scalar::from(0.5).unwrap() * (offset + lhs + stairs.abs()))
+    }
+}
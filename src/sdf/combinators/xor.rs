@@ -0,0 +1,84 @@
+use crate::{Sdf, SdfState};
+use num::Float;
+use std::marker::PhantomData;
+
+/// The symmetric difference of two SDFs, which is inside of exactly one of them. The state
+/// blender receives the distances and states of both SDFs.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Xor<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State)) -> State,
+{
+    lhs: Lhs,
+    rhs: Rhs,
+    state_blender: B,
+    _marker: PhantomData<(Scalar, State)>,
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B> Sdf<Scalar, DIM, State>
+    for Xor<Scalar, Lhs, Rhs, DIM, State, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State)) -> State,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        lhs_distance
+            .min(rhs_distance)
+            .max(-lhs_distance.max(rhs_distance))
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        let lhs_distance = self.lhs.distance_from_slice(point);
+        let rhs_distance = self.rhs.distance_from_slice(point);
+
+        let lhs_state = self.lhs.state(point);
+        let rhs_state = self.rhs.state(point);
+
+        (self.state_blender)((lhs_distance, lhs_state), (rhs_distance, rhs_state))
+    }
+
+    #[inline]
+    fn distance_and_state(&self, point: impl Into<[Scalar; DIM]>) -> (Scalar, State) {
+        let point = point.into();
+
+        let lhs_distance = self.lhs.distance_from_slice(&point);
+        let rhs_distance = self.rhs.distance_from_slice(&point);
+
+        let lhs_state = self.lhs.state(&point);
+        let rhs_state = self.rhs.state(&point);
+
+        let distance = lhs_distance
+            .min(rhs_distance)
+            .max(-lhs_distance.max(rhs_distance));
+        let state = (self.state_blender)((lhs_distance, lhs_state), (rhs_distance, rhs_state));
+
+        (distance, state)
+    }
+}
+
+impl<Scalar: Float, Lhs, Rhs, const DIM: usize, State: SdfState, B>
+    Xor<Scalar, Lhs, Rhs, DIM, State, B>
+where
+    Lhs: Sdf<Scalar, DIM, State>,
+    Rhs: Sdf<Scalar, DIM, State>,
+    B: Fn((Scalar, State), (Scalar, State)) -> State,
+{
+    #[inline]
+    pub fn new(lhs: Lhs, rhs: Rhs, state_blender: B) -> Self {
+        Self {
+            lhs,
+            rhs,
+            state_blender,
+            _marker: PhantomData,
+        }
+    }
+}
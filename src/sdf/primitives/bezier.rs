@@ -0,0 +1,233 @@
+use crate::{
+    Sdf,
+    math::{dot, segment_distance_squared, sub},
+};
+use num::Float;
+
+/// The number of evenly spaced samples used to find the starting points of the minimization of
+/// the distance to a cubic Bézier curve.
+const CUBIC_SAMPLES: usize = 16;
+
+/// The number of Newton iterations used to refine each starting point.
+const CUBIC_NEWTON_ITERATIONS: usize = 6;
+
+/// A SDF Primitive of a quadratic Bézier curve going from `start` to `end` and pulled towards the
+/// control point. The distance is never negative, see [`Segment`](super::Segment).
+///
+/// The closest point of the curve is found exactly, by solving the cubic equation of the
+/// derivative of the squared distance in closed form.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuadraticBezier<Scalar: Float, const DIM: usize> {
+    start: [Scalar; DIM],
+    control: [Scalar; DIM],
+    end: [Scalar; DIM],
+}
+
+impl<Scalar: Float, const DIM: usize> Sdf<Scalar, DIM> for QuadraticBezier<Scalar, DIM> {
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let two = Scalar::from(2.0).unwrap();
+        let three = Scalar::from(3.0).unwrap();
+
+        // With B(t) = P0 + 2tA + t²B, the derivative of |B(t) - p|² / 2 is the cubic
+        // (B.B)t³ + 3(A.B)t² + (2A.A + d.B)t + d.A where d = P0 - p.
+        let a: [Scalar; DIM] = std::array::from_fn(|i| self.control[i] - self.start[i]);
+        let b: [Scalar; DIM] =
+            std::array::from_fn(|i| self.start[i] - two * self.control[i] + self.end[i]);
+        let d: [Scalar; DIM] = std::array::from_fn(|i| self.start[i] - point[i]);
+
+        let bb = dot(&b, &b);
+
+        // Without curvature, the curve is the segment between its ends.
+        if bb <= Scalar::epsilon() * dot(&a, &a) {
+            return segment_distance_squared(&self.start, &self.end, point).sqrt();
+        }
+
+        let roots = solve_monic_cubic(
+            three * dot(&a, &b) / bb,
+            (two * dot(&a, &a) + dot(&d, &b)) / bb,
+            dot(&d, &a) / bb,
+        );
+
+        roots
+            .into_iter()
+            .flatten()
+            .chain([Scalar::zero(), Scalar::one()])
+            .map(|t| {
+                let t = t.max(Scalar::zero()).min(Scalar::one());
+                let offset: [Scalar; DIM] =
+                    std::array::from_fn(|i| d[i] + (two * a[i] + b[i] * t) * t);
+
+                dot(&offset, &offset)
+            })
+            .fold(Scalar::infinity(), |acc, e| acc.min(e))
+            .sqrt()
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; DIM]) {}
+}
+
+impl<Scalar: Float, const DIM: usize> QuadraticBezier<Scalar, DIM> {
+    #[inline]
+    pub fn new(start: [Scalar; DIM], control: [Scalar; DIM], end: [Scalar; DIM]) -> Self {
+        Self {
+            start,
+            control,
+            end,
+        }
+    }
+}
+
+/// A SDF Primitive of a cubic Bézier curve going from `start` to `end`, shaped by two control
+/// points. The distance is never negative, see [`Segment`](super::Segment).
+///
+/// There is no closed form for the closest point of the curve, so it is found numerically: the
+/// curve is sampled to find the local minima of the distance, which are then refined with
+/// Newton's method.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CubicBezier<Scalar: Float, const DIM: usize> {
+    points: [[Scalar; DIM]; 4],
+}
+
+impl<Scalar: Float, const DIM: usize> Sdf<Scalar, DIM> for CubicBezier<Scalar, DIM> {
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let sample_count = Scalar::from(CUBIC_SAMPLES).unwrap();
+        let samples: [Scalar; CUBIC_SAMPLES + 1] = std::array::from_fn(|i| {
+            self.distance_squared(Scalar::from(i).unwrap() / sample_count, point)
+        });
+
+        let mut best = Scalar::infinity();
+
+        for i in 0..=CUBIC_SAMPLES {
+            // Only refine the local minima, including the ends of the curve as the closest point
+            // may lie between them and the next sample.
+            if (i > 0 && samples[i] > samples[i - 1])
+                || (i < CUBIC_SAMPLES && samples[i] > samples[i + 1])
+            {
+                continue;
+            }
+
+            let mut t = Scalar::from(i).unwrap() / sample_count;
+
+            for _ in 0..CUBIC_NEWTON_ITERATIONS {
+                // Minimize |B(t) - p|² by finding a root of its derivative (B(t) - p).B'(t).
+                let position = self.at(t);
+                let offset: [Scalar; DIM] = std::array::from_fn(|i| position[i] - point[i]);
+                let first = self.derivative(t);
+                let second = self.second_derivative(t);
+
+                let numerator = dot(&offset, &first);
+                let denominator = dot(&first, &first) + dot(&offset, &second);
+
+                if denominator <= Scalar::zero() {
+                    break;
+                }
+
+                t = (t - numerator / denominator)
+                    .max(Scalar::zero())
+                    .min(Scalar::one());
+            }
+
+            best = best.min(self.distance_squared(t, point).min(samples[i]));
+        }
+
+        best.sqrt()
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; DIM]) {}
+}
+
+impl<Scalar: Float, const DIM: usize> CubicBezier<Scalar, DIM> {
+    #[inline]
+    pub fn new(
+        start: [Scalar; DIM],
+        start_control: [Scalar; DIM],
+        end_control: [Scalar; DIM],
+        end: [Scalar; DIM],
+    ) -> Self {
+        Self {
+            points: [start, start_control, end_control, end],
+        }
+    }
+
+    /// Returns the point of the curve at a given parameter, between 0 and 1.
+    #[inline]
+    pub fn at(&self, t: Scalar) -> [Scalar; DIM] {
+        let three = Scalar::from(3.0).unwrap();
+        let s = Scalar::one() - t;
+        let weights = [s * s * s, three * s * s * t, three * s * t * t, t * t * t];
+
+        std::array::from_fn(|i| {
+            (0..4).fold(Scalar::zero(), |acc, j| {
+                acc + self.points[j][i] * weights[j]
+            })
+        })
+    }
+
+    #[inline]
+    fn derivative(&self, t: Scalar) -> [Scalar; DIM] {
+        let three = Scalar::from(3.0).unwrap();
+        let six = Scalar::from(6.0).unwrap();
+        let s = Scalar::one() - t;
+        let [p0, p1, p2, p3] = &self.points;
+
+        std::array::from_fn(|i| {
+            three * s * s * (p1[i] - p0[i])
+                + six * s * t * (p2[i] - p1[i])
+                + three * t * t * (p3[i] - p2[i])
+        })
+    }
+
+    #[inline]
+    fn second_derivative(&self, t: Scalar) -> [Scalar; DIM] {
+        let six = Scalar::from(6.0).unwrap();
+        let two = Scalar::from(2.0).unwrap();
+        let s = Scalar::one() - t;
+        let [p0, p1, p2, p3] = &self.points;
+
+        std::array::from_fn(|i| {
+            six * s * (p2[i] - two * p1[i] + p0[i]) + six * t * (p3[i] - two * p2[i] + p1[i])
+        })
+    }
+
+    #[inline]
+    fn distance_squared(&self, t: Scalar, point: &[Scalar; DIM]) -> Scalar {
+        let offset = sub(&self.at(t), point);
+
+        dot(&offset, &offset)
+    }
+}
+
+/// Returns the real roots of t³ + at² + bt + c, using Cardano's method when there is a single real
+/// root and the trigonometric method when there are three.
+fn solve_monic_cubic<Scalar: Float>(a: Scalar, b: Scalar, c: Scalar) -> [Option<Scalar>; 3] {
+    let two = Scalar::from(2.0).unwrap();
+    let three = Scalar::from(3.0).unwrap();
+    let twenty_seven = Scalar::from(27.0).unwrap();
+
+    // Substitute t = u - a/3 to get the depressed cubic u³ + pu + q.
+    let shift = a / three;
+    let p = b - a * a / three;
+    let q = two * a * a * a / twenty_seven - a * b / three + c;
+
+    let discriminant = q * q / Scalar::from(4.0).unwrap() + p * p * p / twenty_seven;
+
+    if discriminant >= Scalar::zero() || p == Scalar::zero() {
+        let root = discriminant.max(Scalar::zero()).sqrt();
+        let u = (-q / two + root).cbrt() + (-q / two - root).cbrt();
+
+        return [Some(u - shift), None, None];
+    }
+
+    let radius = two * (-p / three).sqrt();
+    let angle = ((three * q / (two * p)) * (-three / p).sqrt())
+        .max(-Scalar::one())
+        .min(Scalar::one())
+        .acos()
+        / three;
+    let third_turn = two * Scalar::from(std::f64::consts::PI).unwrap() / three;
+
+    [0.0, 1.0, 2.0]
+        .map(|k| Some(radius * (angle - third_turn * Scalar::from(k).unwrap()).cos() - shift))
+}
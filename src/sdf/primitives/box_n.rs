@@ -0,0 +1,56 @@
+use crate::Sdf;
+use num::Float;
+
+/// A SDF Primitive of an axis aligned box centered at the origin, with independent half extents
+/// along each axis. Unlike [`Cube`](super::Cube), the distance is exact both inside and outside of
+/// the box, which keeps offsets like rounding and thickness accurate.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // A 2x1x3 box.
+/// let cuboid = box_n([1.0, 0.5, 1.5]);
+///
+/// assert_eq!(cuboid.distance([4.0, 4.5, 0.0]), 5.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct BoxN<Scalar: Float, const DIM: usize> {
+    half_extents: [Scalar; DIM],
+}
+
+impl<Scalar: Float, const DIM: usize> Sdf<Scalar, DIM> for BoxN<Scalar, DIM> {
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let (outside, inside) = point.iter().zip(self.half_extents.iter()).fold(
+            (Scalar::zero(), Scalar::neg_infinity()),
+            |(outside, inside), (axis, half_extent)| {
+                let distance = axis.abs() - *half_extent;
+                let clamped = distance.max(Scalar::zero());
+
+                (outside + clamped * clamped, inside.max(distance))
+            },
+        );
+
+        outside.sqrt() + inside.min(Scalar::zero())
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; DIM]) {}
+}
+
+impl<Scalar: Float, const DIM: usize> BoxN<Scalar, DIM> {
+    pub fn new(half_extents: [Scalar; DIM]) -> Self {
+        if half_extents.iter().any(|e| *e < Scalar::zero()) {
+            panic!("Cannot define a box with negative half extents");
+        }
+
+        Self { half_extents }
+    }
+
+    #[inline]
+    pub fn half_extents(&self) -> &[Scalar; DIM] {
+        &self.half_extents
+    }
+}
@@ -0,0 +1,30 @@
+use crate::{Sdf, math::segment_distance_squared};
+use num::Float;
+
+/// A SDF Primitive of a capsule: all the points closer to the segment between `start` and `end`
+/// than the radius.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Capsule<Scalar: Float> {
+    pub start: [Scalar; 3],
+    pub end: [Scalar; 3],
+    pub radius: Scalar,
+}
+
+impl<Scalar: Float> Sdf<Scalar, 3> for Capsule<Scalar> {
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; 3]) -> Scalar {
+        // A degenerate segment is a sphere.
+        segment_distance_squared(&self.start, &self.end, point).sqrt() - self.radius
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; 3]) {}
+}
+
+impl<Scalar: Float> Capsule<Scalar> {
+    #[inline]
+    pub fn new(start: [Scalar; 3], end: [Scalar; 3], radius: Scalar) -> Self {
+        Self { start, end, radius }
+    }
+}
@@ -0,0 +1,72 @@
+use crate::Sdf;
+use num::Float;
+
+/// A SDF Primitive of a capped cone whose axis is Y. The cone is centered at the origin along its
+/// axis, with its apex pointing up at half of its height and its base at minus half of its
+/// height. The angle is the angle between the axis and the side of the cone, in radians.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cone<Scalar: Float> {
+    // NOTE: Like for 2D rotations, the sine and cosine are stored instead of the angle to avoid
+    // computing them on every call to distance.
+    sin: Scalar,
+    cos: Scalar,
+    height: Scalar,
+}
+
+impl<Scalar: Float> Sdf<Scalar, 3> for Cone<Scalar> {
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; 3]) -> Scalar {
+        let zero = Scalar::zero();
+        let one = Scalar::one();
+
+        // Work in the 2D half plane containing the axis and the point, with the apex at the
+        // origin. `q` goes from the apex to the edge of the base.
+        let q = [self.height * self.sin / self.cos, -self.height];
+        let w = [
+            point[0].hypot(point[2]),
+            point[1] - self.height * Scalar::from(0.5).unwrap(),
+        ];
+
+        // Closest point on the side of the cone.
+        let t = ((w[0] * q[0] + w[1] * q[1]) / (q[0] * q[0] + q[1] * q[1]))
+            .max(zero)
+            .min(one);
+        let a = [w[0] - q[0] * t, w[1] - q[1] * t];
+
+        // Closest point on the base of the cone.
+        let t = (w[0] / q[0]).max(zero).min(one);
+        let b = [w[0] - q[0] * t, w[1] - q[1]];
+
+        let distance = (a[0] * a[0] + a[1] * a[1]).min(b[0] * b[0] + b[1] * b[1]);
+
+        // q[1] is always negative as the height is positive.
+        let side = (w[1] * q[0] - w[0] * q[1]).max(q[1] - w[1]);
+
+        distance.sqrt() * side.signum()
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; 3]) {}
+}
+
+impl<Scalar: Float> Cone<Scalar> {
+    #[inline]
+    pub fn new(angle: Scalar, height: Scalar) -> Self {
+        Self {
+            sin: angle.sin(),
+            cos: angle.cos(),
+            height,
+        }
+    }
+
+    #[inline]
+    pub fn angle(&self) -> Scalar {
+        self.sin.atan2(self.cos)
+    }
+
+    #[inline]
+    pub fn height(&self) -> Scalar {
+        self.height
+    }
+}
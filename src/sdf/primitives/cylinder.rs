@@ -0,0 +1,37 @@
+use crate::Sdf;
+use num::Float;
+
+/// A SDF Primitive of a capped cylinder centered at the origin, whose axis is Y. The height is the
+/// full length of the cylinder along its axis.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Cylinder<Scalar: Float> {
+    pub radius: Scalar,
+    pub height: Scalar,
+}
+
+impl<Scalar: Float> Sdf<Scalar, 3> for Cylinder<Scalar> {
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; 3]) -> Scalar {
+        let half_height = self.height * Scalar::from(0.5).unwrap();
+
+        // Distance to the infinite cylinder and to the slab between the caps.
+        let radial = point[0].hypot(point[2]) - self.radius;
+        let axial = point[1].abs() - half_height;
+
+        let inside = radial.max(axial).min(Scalar::zero());
+        let outside = radial.max(Scalar::zero()).hypot(axial.max(Scalar::zero()));
+
+        inside + outside
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; 3]) {}
+}
+
+impl<Scalar: Float> Cylinder<Scalar> {
+    #[inline]
+    pub fn new(radius: Scalar, height: Scalar) -> Self {
+        Self { radius, height }
+    }
+}
@@ -0,0 +1,73 @@
+use crate::Sdf;
+use num::Float;
+
+/// A SDF Primitive of an ellipsoid centered at the origin, with an independent radius along each
+/// axis. The distance uses the approximation by Inigo Quilez, which is exact on the axes and far
+/// away from the surface, and much closer to the true distance than scaling a
+/// [`Sphere`](super::Sphere) along each axis. [`Sphere::scale_axes`](super::Sphere::scale_axes)
+/// builds this primitive directly.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// let ellipsoid = Sphere.scale_axes([1.0, 2.0, 3.0]);
+///
+/// assert_eq!(ellipsoid.distance([0.0, 4.0, 0.0]), 2.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "Scalar: serde::Serialize",
+        deserialize = "Scalar: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Ellipsoid<Scalar: Float, const DIM: usize> {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    radii: [Scalar; DIM],
+}
+
+impl<Scalar: Float, const DIM: usize> Sdf<Scalar, DIM> for Ellipsoid<Scalar, DIM> {
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let (k0, k1) = point.iter().zip(self.radii.iter()).fold(
+            (Scalar::zero(), Scalar::zero()),
+            |(k0, k1), (axis, radius)| {
+                let scaled = *axis / *radius;
+                let twice_scaled = scaled / *radius;
+
+                (k0 + scaled * scaled, k1 + twice_scaled * twice_scaled)
+            },
+        );
+
+        // At the center, the gradient of the implicit function vanishes.
+        if k1 == Scalar::zero() {
+            return -self
+                .radii
+                .iter()
+                .fold(Scalar::infinity(), |acc, e| acc.min(*e));
+        }
+
+        let k0 = k0.sqrt();
+
+        k0 * (k0 - Scalar::one()) / k1.sqrt()
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; DIM]) {}
+}
+
+impl<Scalar: Float, const DIM: usize> Ellipsoid<Scalar, DIM> {
+    #[inline]
+    pub fn new(radii: [Scalar; DIM]) -> Self {
+        Self { radii }
+    }
+
+    #[inline]
+    pub fn radii(&self) -> [Scalar; DIM] {
+        self.radii
+    }
+}
@@ -0,0 +1,375 @@
+use crate::Sdf;
+use num::Float;
+
+/// A source of terrain heights, giving the height of the ground at any point of the XZ plane.
+/// It is implemented for stored samples with [`HeightMap`], and for any `Fn(x, z) -> y` closure.
+pub trait HeightSource<Scalar: Float> {
+    fn height(&self, x: Scalar, z: Scalar) -> Scalar;
+
+    /// Returns a Lipschitz bound of the heights when the source can derive one from its own data,
+    /// in which case it is trusted over a stored one when a [`Heightfield`] is deserialized.
+    #[inline]
+    fn lipschitz_bound(&self) -> Option<Scalar> {
+        None
+    }
+}
+
+impl<Scalar: Float, F: Fn(Scalar, Scalar) -> Scalar> HeightSource<Scalar> for F {
+    #[inline]
+    fn height(&self, x: Scalar, z: Scalar) -> Scalar {
+        self(x, z)
+    }
+}
+
+/// How the heights of a [`HeightMap`] are interpolated between its samples.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Interpolation {
+    #[default]
+    Bilinear,
+    /// Catmull-Rom interpolation, which is smooth across samples but may overshoot them.
+    Bicubic,
+}
+
+/// A grid of height samples stretched over a rectangle of the XZ plane. The samples are ordered
+/// with the X axis varying fastest, like the values of a [`SampledGrid`](crate::grid::SampledGrid),
+/// and the first and last samples of each axis lie on the edges of the rectangle. Outside of the
+/// rectangle, the heights of the closest edge are extended.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "RawHeightMap<Scalar>",
+        bound(deserialize = "Scalar: serde::Deserialize<'de>")
+    )
+)]
+pub struct HeightMap<Scalar: Float> {
+    samples: Vec<Scalar>,
+    resolution: [usize; 2],
+    min: [Scalar; 2],
+    max: [Scalar; 2],
+    interpolation: Interpolation,
+}
+
+/// The fields of a [`HeightMap`] as they are deserialized, before going through the same checks
+/// as [`HeightMap::new`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawHeightMap<Scalar: Float> {
+    samples: Vec<Scalar>,
+    resolution: [usize; 2],
+    min: [Scalar; 2],
+    max: [Scalar; 2],
+    interpolation: Interpolation,
+}
+
+#[cfg(feature = "serde")]
+impl<Scalar: Float> TryFrom<RawHeightMap<Scalar>> for HeightMap<Scalar> {
+    type Error = &'static str;
+
+    fn try_from(raw: RawHeightMap<Scalar>) -> Result<Self, Self::Error> {
+        Self::validate(&raw.samples, raw.resolution)?;
+
+        Ok(Self {
+            samples: raw.samples,
+            resolution: raw.resolution,
+            min: raw.min,
+            max: raw.max,
+            interpolation: raw.interpolation,
+        })
+    }
+}
+
+impl<Scalar: Float> HeightSource<Scalar> for HeightMap<Scalar> {
+    fn height(&self, x: Scalar, z: Scalar) -> Scalar {
+        let ([x, z], [tx, tz]) = self.locate([x, z]);
+
+        match self.interpolation {
+            Interpolation::Bilinear => {
+                let lerp = |a: Scalar, b: Scalar, t: Scalar| a + (b - a) * t;
+                let row = |z| lerp(self.sample(x, z), self.sample(x + 1, z), tx);
+
+                lerp(row(z), row(z + 1), tz)
+            }
+            Interpolation::Bicubic => {
+                let row = |z| catmull_rom([x - 1, x, x + 1, x + 2].map(|x| self.sample(x, z)), tx);
+
+                catmull_rom([z - 1, z, z + 1, z + 2].map(row), tz)
+            }
+        }
+    }
+
+    #[inline]
+    fn lipschitz_bound(&self) -> Option<Scalar> {
+        Some(self.lipschitz())
+    }
+}
+
+impl<Scalar: Float> HeightMap<Scalar> {
+    pub fn new(
+        samples: Vec<Scalar>,
+        resolution: [usize; 2],
+        min: [Scalar; 2],
+        max: [Scalar; 2],
+        interpolation: Interpolation,
+    ) -> Self {
+        if let Err(message) = Self::validate(&samples, resolution) {
+            panic!("{message}");
+        }
+
+        Self {
+            samples,
+            resolution,
+            min,
+            max,
+            interpolation,
+        }
+    }
+
+    /// Checks that the samples fill a grid of the given resolution, with at least 2 samples along
+    /// each axis.
+    fn validate(samples: &[Scalar], resolution: [usize; 2]) -> Result<(), &'static str> {
+        if resolution.iter().any(|&count| count < 2) {
+            return Err("Cannot define a height map with less than 2 samples along an axis");
+        }
+
+        if samples.len() != resolution[0] * resolution[1] {
+            return Err(
+                "Cannot define a height map whose sample count does not match its resolution",
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Samples a height source on a regular grid, for instance to cache an expensive procedural
+    /// terrain.
+    pub fn from_source(
+        source: &impl HeightSource<Scalar>,
+        resolution: [usize; 2],
+        min: [Scalar; 2],
+        max: [Scalar; 2],
+        interpolation: Interpolation,
+    ) -> Self {
+        let position = |axis: usize, index: usize| {
+            let t = Scalar::from(index).unwrap() / Scalar::from(resolution[axis] - 1).unwrap();
+            min[axis] + (max[axis] - min[axis]) * t
+        };
+
+        let samples = (0..resolution[1])
+            .flat_map(|z| (0..resolution[0]).map(move |x| (x, z)))
+            .map(|(x, z)| source.height(position(0, x), position(1, z)))
+            .collect();
+
+        Self::new(samples, resolution, min, max, interpolation)
+    }
+
+    #[inline]
+    pub fn samples(&self) -> &[Scalar] {
+        &self.samples
+    }
+
+    #[inline]
+    pub fn resolution(&self) -> [usize; 2] {
+        self.resolution
+    }
+
+    #[inline]
+    pub fn min(&self) -> [Scalar; 2] {
+        self.min
+    }
+
+    #[inline]
+    pub fn max(&self) -> [Scalar; 2] {
+        self.max
+    }
+
+    #[inline]
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    /// Returns an upper bound of the slope of the interpolated heights, which is the largest slope
+    /// between two neighbouring samples for bilinear interpolation. Catmull-Rom splines can be up
+    /// to twice as steep as their samples along one axis, and their weights sum up to at most 1.25
+    /// in absolute value along the other, so the bound is scaled by 2.5 for bicubic interpolation.
+    pub fn lipschitz(&self) -> Scalar {
+        let [width, depth] = self.resolution;
+        let cell_size: [Scalar; 2] = std::array::from_fn(|axis| {
+            (self.max[axis] - self.min[axis]) / Scalar::from(self.resolution[axis] - 1).unwrap()
+        });
+
+        let steepest = |dx: isize, dz: isize| {
+            (0..depth as isize - dz)
+                .flat_map(|z| (0..width as isize - dx).map(move |x| (x, z)))
+                .map(|(x, z)| (self.sample(x + dx, z + dz) - self.sample(x, z)).abs())
+                .fold(Scalar::zero(), |acc, e| acc.max(e))
+        };
+
+        let slope =
+            (steepest(1, 0) / cell_size[0].abs()).hypot(steepest(0, 1) / cell_size[1].abs());
+
+        match self.interpolation {
+            Interpolation::Bilinear => slope,
+            Interpolation::Bicubic => slope * Scalar::from(2.5).unwrap(),
+        }
+    }
+
+    /// Returns the sample at the given indices, clamping them to the grid.
+    #[inline]
+    fn sample(&self, x: isize, z: isize) -> Scalar {
+        let clamp = |index: isize, count: usize| index.clamp(0, count as isize - 1) as usize;
+
+        self.samples
+            [clamp(x, self.resolution[0]) + clamp(z, self.resolution[1]) * self.resolution[0]]
+    }
+
+    /// Returns the indices of the cell containing a point, and the position of the point within
+    /// that cell. Points outside of the map are clamped to its edges.
+    #[inline]
+    fn locate(&self, point: [Scalar; 2]) -> ([isize; 2], [Scalar; 2]) {
+        let mut cell = [0; 2];
+        let mut offset = [Scalar::zero(); 2];
+
+        for axis in 0..2 {
+            let last = self.resolution[axis] - 1;
+            let position = ((point[axis] - self.min[axis]) / (self.max[axis] - self.min[axis])
+                * Scalar::from(last).unwrap())
+            .max(Scalar::zero())
+            .min(Scalar::from(last).unwrap());
+
+            // The last sample is reached from the last cell to always have a next sample.
+            let index = position.floor().to_usize().unwrap().min(last - 1);
+
+            cell[axis] = index as isize;
+            offset[axis] = position - Scalar::from(index).unwrap();
+        }
+
+        (cell, offset)
+    }
+}
+
+/// Interpolates between the two middle values with a Catmull-Rom spline.
+#[inline]
+fn catmull_rom<Scalar: Float>([a, b, c, d]: [Scalar; 4], t: Scalar) -> Scalar {
+    let half = Scalar::from(0.5).unwrap();
+    let two = Scalar::from(2.0).unwrap();
+    let three = Scalar::from(3.0).unwrap();
+
+    let ta = (c - a) * half;
+    let tb = (d - b) * half;
+
+    // Cubic Hermite spline with the tangents of Catmull-Rom.
+    let t2 = t * t;
+    let t3 = t2 * t;
+
+    (two * t3 - three * t2 + Scalar::one()) * b
+        + (t3 - two * t2 + t) * ta
+        + (three * t2 - two * t3) * c
+        + (t3 - t2) * tb
+}
+
+/// A SDF Primitive of a terrain, which is solid below the height given at each point of the XZ
+/// plane. The vertical offset to the terrain is divided by the Lipschitz bound of the heights,
+/// which keeps the distance conservative so that marching never steps through slopes.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // Rolling hills from a closure, whose slope never exceeds 0.5.
+/// let hills = Heightfield::from_fn(|x: f32, z: f32| 0.5 * x.sin() * z.cos(), 0.5);
+///
+/// // The same hills cached in a height map.
+/// let map = HeightMap::from_source(
+///     &|x: f32, z: f32| 0.5 * x.sin() * z.cos(),
+///     [64, 64],
+///     [-8.0, -8.0],
+///     [8.0, 8.0],
+///     Interpolation::Bicubic,
+/// );
+/// let terrain = heightfield(map).add(Sphere.translate(&[0.0, 1.0, 0.0]));
+///
+/// assert!(hills.distance([0.0, 1.0, 0.0]) > 0.0);
+/// assert!(terrain.distance([0.0, -1.0, 0.0]) < 0.0);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(from = "RawHeightfield<Scalar, Source>")
+)]
+pub struct Heightfield<Scalar: Float, Source = HeightMap<Scalar>> {
+    #[cfg_attr(
+        feature = "serde",
+        serde(bound(deserialize = "Source: serde::Deserialize<'de> + HeightSource<Scalar>"))
+    )]
+    source: Source,
+    lipschitz: Scalar,
+}
+
+/// The fields of a [`Heightfield`] as they are deserialized, before the Lipschitz bound is derived
+/// again from the source when it can be.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawHeightfield<Scalar, Source> {
+    source: Source,
+    lipschitz: Scalar,
+}
+
+#[cfg(feature = "serde")]
+impl<Scalar: Float, Source: HeightSource<Scalar>> From<RawHeightfield<Scalar, Source>>
+    for Heightfield<Scalar, Source>
+{
+    fn from(raw: RawHeightfield<Scalar, Source>) -> Self {
+        Self {
+            lipschitz: raw.source.lipschitz_bound().unwrap_or(raw.lipschitz),
+            source: raw.source,
+        }
+    }
+}
+
+impl<Scalar: Float, Source: HeightSource<Scalar>> Sdf<Scalar, 3> for Heightfield<Scalar, Source> {
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; 3]) -> Scalar {
+        let offset = point[1] - self.source.height(point[0], point[2]);
+
+        // The offset grows at most by sqrt(1 + L²) per unit of distance.
+        offset / self.lipschitz.hypot(Scalar::one())
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; 3]) {}
+}
+
+impl<Scalar: Float> Heightfield<Scalar> {
+    /// Creates the terrain of a height map, whose Lipschitz bound is derived from its samples.
+    #[inline]
+    pub fn new(map: HeightMap<Scalar>) -> Self {
+        Self {
+            lipschitz: map.lipschitz(),
+            source: map,
+        }
+    }
+}
+
+impl<Scalar: Float, Source: HeightSource<Scalar>> Heightfield<Scalar, Source> {
+    /// Creates the terrain of any height source. The Lipschitz bound must be at least the steepest
+    /// slope of the heights, or the distance will overshoot the terrain.
+    #[inline]
+    pub fn from_fn(source: Source, lipschitz: Scalar) -> Self {
+        Self { source, lipschitz }
+    }
+
+    #[inline]
+    pub fn source(&self) -> &Source {
+        &self.source
+    }
+
+    #[inline]
+    pub fn lipschitz(&self) -> Scalar {
+        self.lipschitz
+    }
+}
@@ -1,12 +1,34 @@
+mod bezier;
+mod box_n;
+mod capsule;
+mod cone;
 mod cube;
+mod cylinder;
+mod ellipsoid;
+mod heightfield;
 mod line;
 mod plane;
+mod polygon;
+mod segment;
 mod sphere;
+mod torus;
+mod triangle_mesh;
 
+pub use bezier::{CubicBezier, QuadraticBezier};
+pub use box_n::BoxN;
+pub use capsule::Capsule;
+pub use cone::Cone;
 pub use cube::Cube;
+pub use cylinder::Cylinder;
+pub use ellipsoid::Ellipsoid;
+pub use heightfield::{HeightMap, HeightSource, Heightfield, Interpolation};
 pub use line::Line;
 pub use plane::Plane;
+pub use polygon::Polygon;
+pub use segment::Segment;
 pub use sphere::Sphere;
+pub use torus::Torus;
+pub use triangle_mesh::TriangleMesh;
 
 use num::Float;
 
@@ -20,7 +42,86 @@ pub fn cube() -> Cube {
     Cube
 }
 
+#[inline]
+pub fn box_n<Scalar: Float, const DIM: usize>(half_extents: [Scalar; DIM]) -> BoxN<Scalar, DIM> {
+    BoxN::new(half_extents)
+}
+
+#[inline]
+pub fn ellipsoid<Scalar: Float, const DIM: usize>(radii: [Scalar; DIM]) -> Ellipsoid<Scalar, DIM> {
+    Ellipsoid::new(radii)
+}
+
 #[inline]
 pub fn line<Scalar: Float, const DIM: usize>(direction: [Scalar; DIM]) -> Line<Scalar, DIM> {
     Line::new(direction)
 }
+
+#[inline]
+pub fn torus<Scalar: Float>(major_radius: Scalar, minor_radius: Scalar) -> Torus<Scalar> {
+    Torus::new(major_radius, minor_radius)
+}
+
+#[inline]
+pub fn capsule<Scalar: Float>(
+    start: [Scalar; 3],
+    end: [Scalar; 3],
+    radius: Scalar,
+) -> Capsule<Scalar> {
+    Capsule::new(start, end, radius)
+}
+
+#[inline]
+pub fn cylinder<Scalar: Float>(radius: Scalar, height: Scalar) -> Cylinder<Scalar> {
+    Cylinder::new(radius, height)
+}
+
+#[inline]
+pub fn cone<Scalar: Float>(angle: Scalar, height: Scalar) -> Cone<Scalar> {
+    Cone::new(angle, height)
+}
+
+#[inline]
+pub fn heightfield<Scalar: Float>(map: HeightMap<Scalar>) -> Heightfield<Scalar> {
+    Heightfield::new(map)
+}
+
+#[inline]
+pub fn polygon<Scalar: Float>(outline: Vec<[Scalar; 2]>) -> Polygon<Scalar> {
+    Polygon::new(outline)
+}
+
+#[inline]
+pub fn segment<Scalar: Float, const DIM: usize>(
+    start: [Scalar; DIM],
+    end: [Scalar; DIM],
+) -> Segment<Scalar, DIM> {
+    Segment::new(start, end)
+}
+
+#[inline]
+pub fn quadratic_bezier<Scalar: Float, const DIM: usize>(
+    start: [Scalar; DIM],
+    control: [Scalar; DIM],
+    end: [Scalar; DIM],
+) -> QuadraticBezier<Scalar, DIM> {
+    QuadraticBezier::new(start, control, end)
+}
+
+#[inline]
+pub fn cubic_bezier<Scalar: Float, const DIM: usize>(
+    start: [Scalar; DIM],
+    start_control: [Scalar; DIM],
+    end_control: [Scalar; DIM],
+    end: [Scalar; DIM],
+) -> CubicBezier<Scalar, DIM> {
+    CubicBezier::new(start, start_control, end_control, end)
+}
+
+#[inline]
+pub fn triangle_mesh<Scalar: Float>(
+    positions: Vec<[Scalar; 3]>,
+    indices: Vec<[u32; 3]>,
+) -> TriangleMesh<Scalar> {
+    TriangleMesh::new(positions, indices)
+}
@@ -0,0 +1,158 @@
+use crate::{Sdf, math::segment_distance_squared};
+use num::Float;
+
+/// A SDF Primitive of an arbitrary 2D polygon, optionally with holes. The outline and the holes
+/// are closed rings of vertices: the last vertex connects back to the first one. Their orientation
+/// does not matter, and they may be concave or touch themselves.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // A square with a square hole.
+/// let frame = Polygon::with_holes(
+///     vec![[-2.0, -2.0], [2.0, -2.0], [2.0, 2.0], [-2.0, 2.0]],
+///     vec![vec![[-1.0, -1.0], [1.0, -1.0], [1.0, 1.0], [-1.0, 1.0]]],
+/// );
+///
+/// assert_eq!(frame.distance([0.0, 0.0]), 1.0);
+/// assert_eq!(frame.distance([1.5, 0.0]), -0.5);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "RawPolygon<Scalar>",
+        bound(deserialize = "Scalar: serde::Deserialize<'de>")
+    )
+)]
+pub struct Polygon<Scalar: Float> {
+    outline: Vec<[Scalar; 2]>,
+    holes: Vec<Vec<[Scalar; 2]>>,
+}
+
+/// The fields of a [`Polygon`] as they are deserialized, before going through the same checks as
+/// [`Polygon::with_holes`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+#[serde(bound(deserialize = "Scalar: serde::Deserialize<'de>"))]
+struct RawPolygon<Scalar: Float> {
+    outline: Vec<[Scalar; 2]>,
+    #[serde(default)]
+    holes: Vec<Vec<[Scalar; 2]>>,
+}
+
+#[cfg(feature = "serde")]
+impl<Scalar: Float> TryFrom<RawPolygon<Scalar>> for Polygon<Scalar> {
+    type Error = &'static str;
+
+    fn try_from(raw: RawPolygon<Scalar>) -> Result<Self, Self::Error> {
+        Self::validate(&raw.outline, &raw.holes)?;
+
+        Ok(Self {
+            outline: raw.outline,
+            holes: raw.holes,
+        })
+    }
+}
+
+impl<Scalar: Float> Sdf<Scalar, 2> for Polygon<Scalar> {
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; 2]) -> Scalar {
+        let distance_squared = self
+            .rings()
+            .map(|ring| ring_distance_squared(ring, point))
+            .fold(Scalar::infinity(), |acc, e| acc.min(e));
+
+        // Points are inside if they are wrapped by the outline, and by none of the holes.
+        let inside = winding_number(&self.outline, point) != 0
+            && self
+                .holes
+                .iter()
+                .all(|hole| winding_number(hole, point) == 0);
+
+        if inside {
+            -distance_squared.sqrt()
+        } else {
+            distance_squared.sqrt()
+        }
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; 2]) {}
+}
+
+impl<Scalar: Float> Polygon<Scalar> {
+    #[inline]
+    pub fn new(outline: Vec<[Scalar; 2]>) -> Self {
+        Self::with_holes(outline, Vec::new())
+    }
+
+    pub fn with_holes(outline: Vec<[Scalar; 2]>, holes: Vec<Vec<[Scalar; 2]>>) -> Self {
+        if let Err(message) = Self::validate(&outline, &holes) {
+            panic!("{message}");
+        }
+
+        Self { outline, holes }
+    }
+
+    /// Checks that the outline and every hole have at least 3 vertices.
+    fn validate(outline: &[[Scalar; 2]], holes: &[Vec<[Scalar; 2]>]) -> Result<(), &'static str> {
+        if outline.len() < 3 || holes.iter().any(|hole| hole.len() < 3) {
+            return Err("Cannot define a polygon ring with less than 3 vertices");
+        }
+
+        Ok(())
+    }
+
+    #[inline]
+    pub fn outline(&self) -> &[[Scalar; 2]] {
+        &self.outline
+    }
+
+    #[inline]
+    pub fn holes(&self) -> &[Vec<[Scalar; 2]>] {
+        &self.holes
+    }
+
+    #[inline]
+    fn rings(&self) -> impl Iterator<Item = &[[Scalar; 2]]> {
+        std::iter::once(self.outline.as_slice()).chain(self.holes.iter().map(Vec::as_slice))
+    }
+}
+
+/// Iterates over the edges of a closed ring.
+#[inline]
+fn edges<Scalar: Float>(
+    ring: &[[Scalar; 2]],
+) -> impl Iterator<Item = (&[Scalar; 2], &[Scalar; 2])> {
+    ring.iter().zip(ring.iter().cycle().skip(1))
+}
+
+/// Returns the squared distance from the point to the closest edge of the ring.
+fn ring_distance_squared<Scalar: Float>(ring: &[[Scalar; 2]], point: &[Scalar; 2]) -> Scalar {
+    edges(ring)
+        .map(|(start, end)| segment_distance_squared(start, end, point))
+        .fold(Scalar::infinity(), |acc, e| acc.min(e))
+}
+
+/// Counts how many times the ring winds around the point, counter-clockwise turns being positive.
+fn winding_number<Scalar: Float>(ring: &[[Scalar; 2]], point: &[Scalar; 2]) -> i32 {
+    edges(ring).fold(0, |winding, (start, end)| {
+        // Positive when the point is on the left of the edge.
+        let side = (end[0] - start[0]) * (point[1] - start[1])
+            - (point[0] - start[0]) * (end[1] - start[1]);
+
+        if start[1] <= point[1] {
+            if end[1] > point[1] && side > Scalar::zero() {
+                return winding + 1;
+            }
+        } else if end[1] <= point[1] && side < Scalar::zero() {
+            return winding - 1;
+        }
+
+        winding
+    })
+}
@@ -0,0 +1,38 @@
+use crate::{Sdf, math::segment_distance_squared};
+use num::Float;
+
+/// A SDF Primitive of the finite segment between two points. The distance is never negative, so
+/// the segment is usually given some volume with
+/// [thickness](crate::sdf::transformers::SdfTransformOperations::thickness).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Segment<Scalar: Float, const DIM: usize> {
+    start: [Scalar; DIM],
+    end: [Scalar; DIM],
+}
+
+impl<Scalar: Float, const DIM: usize> Sdf<Scalar, DIM> for Segment<Scalar, DIM> {
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        segment_distance_squared(&self.start, &self.end, point).sqrt()
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; DIM]) {}
+}
+
+impl<Scalar: Float, const DIM: usize> Segment<Scalar, DIM> {
+    #[inline]
+    pub fn new(start: [Scalar; DIM], end: [Scalar; DIM]) -> Self {
+        Self { start, end }
+    }
+
+    #[inline]
+    pub fn start(&self) -> &[Scalar; DIM] {
+        &self.start
+    }
+
+    #[inline]
+    pub fn end(&self) -> &[Scalar; DIM] {
+        &self.end
+    }
+}
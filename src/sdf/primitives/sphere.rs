@@ -1,6 +1,8 @@
 use crate::Sdf;
 use num::Float;
 
+use super::Ellipsoid;
+
 /// A SDF Primitive of a sphere of radius 1 centered at the origin. Both the radius and the center
 /// can be modified by scaling and translating the SDF (see
 /// [this trait](`crate::prelude::SdfTransformOperations`)) for the methods needed to achieve this.
@@ -30,3 +32,16 @@ impl<Scalar: Float, const DIM: usize> Sdf<Scalar, DIM> for Sphere {
     #[inline]
     fn state(&self, _: &[Scalar; DIM]) {}
 }
+
+impl Sphere {
+    /// Scales the sphere by a different factor along each axis. This takes precedence over
+    /// [`SdfTransformOperations::scale_axes`](crate::prelude::SdfTransformOperations::scale_axes),
+    /// and gives an [`Ellipsoid`] whose distance is much closer to the true one.
+    #[inline]
+    pub fn scale_axes<Scalar: Float, const DIM: usize>(
+        self,
+        scale: impl Into<[Scalar; DIM]>,
+    ) -> Ellipsoid<Scalar, DIM> {
+        Ellipsoid::new(scale.into())
+    }
+}
@@ -0,0 +1,34 @@
+use crate::Sdf;
+use num::Float;
+
+/// A SDF Primitive of a torus centered at the origin and lying in the XZ plane, so that Y is its
+/// axis of revolution. The major radius is the distance from the center to the center of the
+/// tube, and the minor radius is the radius of the tube.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Torus<Scalar: Float> {
+    pub major_radius: Scalar,
+    pub minor_radius: Scalar,
+}
+
+impl<Scalar: Float> Sdf<Scalar, 3> for Torus<Scalar> {
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; 3]) -> Scalar {
+        let ring = point[0].hypot(point[2]) - self.major_radius;
+
+        ring.hypot(point[1]) - self.minor_radius
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; 3]) {}
+}
+
+impl<Scalar: Float> Torus<Scalar> {
+    #[inline]
+    pub fn new(major_radius: Scalar, minor_radius: Scalar) -> Self {
+        Self {
+            major_radius,
+            minor_radius,
+        }
+    }
+}
@@ -0,0 +1,167 @@
+use num::Float;
+
+/// The maximum number of triangles stored in a leaf of the hierarchy.
+const LEAF_SIZE: usize = 4;
+
+/// The maximum depth of the traversal stack. Nodes are split at their median, so the depth of the
+/// hierarchy grows with the logarithm of the triangle count and never comes close to this.
+const STACK_SIZE: usize = 64;
+
+#[derive(Debug, Clone, PartialEq)]
+struct Node<Scalar: Float> {
+    min: [Scalar; 3],
+    max: [Scalar; 3],
+    /// For leaves, the index of the first triangle of the leaf. For other nodes, the index of the
+    /// second child, the first child always directly following its parent.
+    start: u32,
+    /// The number of triangles of a leaf, or 0 for other nodes.
+    count: u32,
+}
+
+impl<Scalar: Float> Node<Scalar> {
+    /// Returns the squared distance from the point to the bounding box of the node.
+    #[inline]
+    fn distance_squared(&self, point: &[Scalar; 3]) -> Scalar {
+        (0..3)
+            .map(|i| {
+                (self.min[i] - point[i])
+                    .max(point[i] - self.max[i])
+                    .max(Scalar::zero())
+            })
+            .fold(Scalar::zero(), |acc, e| acc + e * e)
+    }
+}
+
+/// A bounding volume hierarchy of axis aligned boxes over the triangles of a mesh.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) struct Bvh<Scalar: Float> {
+    nodes: Vec<Node<Scalar>>,
+    /// The indices of the triangles, reordered so that each leaf covers a contiguous range.
+    triangles: Vec<u32>,
+}
+
+impl<Scalar: Float> Bvh<Scalar> {
+    /// Builds the hierarchy from the bounding boxes of every triangle.
+    pub(super) fn new(bounds: &[([Scalar; 3], [Scalar; 3])]) -> Self {
+        let mut bvh = Self {
+            nodes: Vec::with_capacity(2 * bounds.len() / LEAF_SIZE + 1),
+            triangles: (0..bounds.len() as u32).collect(),
+        };
+
+        let mut triangles = std::mem::take(&mut bvh.triangles);
+        bvh.build(bounds, &mut triangles, 0);
+        bvh.triangles = triangles;
+
+        bvh
+    }
+
+    fn build(
+        &mut self,
+        bounds: &[([Scalar; 3], [Scalar; 3])],
+        triangles: &mut [u32],
+        offset: usize,
+    ) -> usize {
+        let (min, max) = triangles.iter().fold(
+            ([Scalar::infinity(); 3], [Scalar::neg_infinity(); 3]),
+            |(min, max), &triangle| {
+                let (triangle_min, triangle_max) = &bounds[triangle as usize];
+                (
+                    std::array::from_fn(|i| min[i].min(triangle_min[i])),
+                    std::array::from_fn(|i| max[i].max(triangle_max[i])),
+                )
+            },
+        );
+
+        let index = self.nodes.len();
+        self.nodes.push(Node {
+            min,
+            max,
+            start: offset as u32,
+            count: triangles.len() as u32,
+        });
+
+        if triangles.len() <= LEAF_SIZE {
+            return index;
+        }
+
+        // Split at the median of the centroids along the longest axis of the node.
+        let axis = (0..3)
+            .max_by(|&a, &b| {
+                (max[a] - min[a])
+                    .partial_cmp(&(max[b] - min[b]))
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            })
+            .unwrap();
+
+        let centroid = |triangle: &u32| {
+            let (min, max) = &bounds[*triangle as usize];
+            min[axis] + max[axis]
+        };
+
+        triangles.sort_unstable_by(|a, b| {
+            centroid(a)
+                .partial_cmp(&centroid(b))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        });
+
+        let middle = triangles.len() / 2;
+        let (left, right) = triangles.split_at_mut(middle);
+
+        self.build(bounds, left, offset);
+        let second = self.build(bounds, right, offset + middle);
+
+        self.nodes[index].start = second as u32;
+        self.nodes[index].count = 0;
+
+        index
+    }
+
+    /// Returns the triangle closest to the point, along with its squared distance. The squared
+    /// distance from the point to a triangle is given by the closure.
+    pub(super) fn closest(
+        &self,
+        point: &[Scalar; 3],
+        mut distance_squared: impl FnMut(u32) -> Scalar,
+    ) -> (u32, Scalar) {
+        let mut best = (0, Scalar::infinity());
+
+        let mut stack = [0; STACK_SIZE];
+        let mut stack_len = 1;
+
+        while stack_len > 0 {
+            stack_len -= 1;
+            let index = stack[stack_len];
+            let node = &self.nodes[index];
+
+            if node.distance_squared(point) >= best.1 {
+                continue;
+            }
+
+            if node.count > 0 {
+                let start = node.start as usize;
+                for &triangle in &self.triangles[start..start + node.count as usize] {
+                    let distance = distance_squared(triangle);
+                    if distance < best.1 {
+                        best = (triangle, distance);
+                    }
+                }
+                continue;
+            }
+
+            // Visit the closest child first, as it is the most likely to shrink the best distance
+            // and prune the other one.
+            let mut children = [index + 1, node.start as usize];
+            let distances = children.map(|child| self.nodes[child].distance_squared(point));
+            if distances[0] < distances[1] {
+                children.swap(0, 1);
+            }
+
+            for child in children {
+                stack[stack_len] = child;
+                stack_len += 1;
+            }
+        }
+
+        best
+    }
+}
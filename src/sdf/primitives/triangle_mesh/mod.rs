@@ -0,0 +1,264 @@
+mod bvh;
+
+use std::collections::HashMap;
+
+use num::Float;
+
+use crate::{
+    Sdf,
+    math::{add, cross, dot, normalize, scale, sub},
+    mesh::Mesh,
+};
+
+use bvh::Bvh;
+
+/// The part of a triangle a point is closest to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Feature {
+    Vertex(usize),
+    /// The edge going from the vertex of the same index to the next one.
+    Edge(usize),
+    Face,
+}
+
+/// A SDF Primitive of a closed triangle mesh, used to import existing assets. The closest
+/// triangle is found with a bounding volume hierarchy, and the sign is given by the angle weighted
+/// pseudo-normal of the closest vertex, edge or face. The mesh must be closed and its triangles
+/// must be wound counter-clockwise when seen from outside for the sign to be correct.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// let grid = Grid::new([-1.5; 3], [1.5; 3], [16; 3]);
+/// let mesh = Sphere.marching_cubes(&grid);
+///
+/// let imported = TriangleMesh::from_mesh(&mesh).translate(&[0.5, 0.0, 0.0]).add(Cube);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct TriangleMesh<Scalar: Float> {
+    positions: Vec<[Scalar; 3]>,
+    indices: Vec<[u32; 3]>,
+
+    face_normals: Vec<[Scalar; 3]>,
+    /// The pseudo-normal of each edge of each triangle, in the order of [`Feature::Edge`].
+    edge_normals: Vec<[[Scalar; 3]; 3]>,
+    vertex_normals: Vec<[Scalar; 3]>,
+
+    bvh: Bvh<Scalar>,
+}
+
+impl<Scalar: Float> Sdf<Scalar, 3> for TriangleMesh<Scalar> {
+    fn distance_from_slice(&self, point: &[Scalar; 3]) -> Scalar {
+        let (triangle, distance_squared) = self.bvh.closest(point, |triangle| {
+            let (closest, _) = self.closest_point(triangle as usize, point);
+            let offset = sub(point, &closest);
+
+            dot(&offset, &offset)
+        });
+
+        let (closest, feature) = self.closest_point(triangle as usize, point);
+        let indices = self.indices[triangle as usize];
+
+        let normal = match feature {
+            Feature::Vertex(i) => &self.vertex_normals[indices[i] as usize],
+            Feature::Edge(i) => &self.edge_normals[triangle as usize][i],
+            Feature::Face => &self.face_normals[triangle as usize],
+        };
+
+        let distance = distance_squared.sqrt();
+
+        if dot(&sub(point, &closest), normal) < Scalar::zero() {
+            -distance
+        } else {
+            distance
+        }
+    }
+
+    #[inline]
+    fn state(&self, _: &[Scalar; 3]) {}
+}
+
+impl<Scalar: Float> TriangleMesh<Scalar> {
+    /// Creates the SDF of a triangle mesh. Vertices sharing the same position are merged and
+    /// triangles without any area are removed, as both break the connectivity the sign relies on.
+    pub fn new(positions: Vec<[Scalar; 3]>, indices: Vec<[u32; 3]>) -> Self {
+        if indices
+            .iter()
+            .flatten()
+            .any(|&i| i as usize >= positions.len())
+        {
+            panic!("Cannot define a triangle mesh with out of bounds indices");
+        }
+
+        // Adding zero turns negative zeros into positive ones so both are merged.
+        let mut welded = HashMap::new();
+        let mut unique_positions = Vec::new();
+        let remap: Vec<u32> = positions
+            .iter()
+            .map(|position| {
+                let key = position.map(|axis| (axis + Scalar::zero()).integer_decode());
+                *welded.entry(key).or_insert_with(|| {
+                    unique_positions.push(*position);
+                    unique_positions.len() as u32 - 1
+                })
+            })
+            .collect();
+        let positions = unique_positions;
+
+        let corners = |triangle: &[u32; 3]| triangle.map(|i| positions[i as usize]);
+
+        let (indices, face_normals): (Vec<_>, Vec<_>) = indices
+            .iter()
+            .map(|triangle| triangle.map(|i| remap[i as usize]))
+            .filter_map(|triangle| {
+                let [a, b, c] = corners(&triangle);
+                let normal = cross(&sub(&b, &a), &sub(&c, &a));
+
+                if normal.iter().all(|axis| *axis == Scalar::zero()) {
+                    return None;
+                }
+
+                Some((triangle, normalize(&normal)))
+            })
+            .unzip();
+
+        if indices.is_empty() {
+            panic!("Cannot define a triangle mesh without any triangle");
+        }
+
+        // Edge pseudo-normals are the sum of the normals of the two faces sharing the edge, and
+        // vertex pseudo-normals the sum of the normals of the faces around the vertex weighted by
+        // their angle at the vertex.
+        let mut shared_edges: HashMap<(u32, u32), [Scalar; 3]> = HashMap::new();
+        let mut vertex_normals = vec![[Scalar::zero(); 3]; positions.len()];
+
+        for (triangle, normal) in indices.iter().zip(&face_normals) {
+            let vertices = corners(triangle);
+
+            for i in 0..3 {
+                let (start, end) = (triangle[i], triangle[(i + 1) % 3]);
+                let edge = shared_edges
+                    .entry((start.min(end), start.max(end)))
+                    .or_insert([Scalar::zero(); 3]);
+                *edge = add(edge, normal);
+
+                let to_next = normalize(&sub(&vertices[(i + 1) % 3], &vertices[i]));
+                let to_previous = normalize(&sub(&vertices[(i + 2) % 3], &vertices[i]));
+                let angle = dot(&to_next, &to_previous)
+                    .max(-Scalar::one())
+                    .min(Scalar::one())
+                    .acos();
+
+                let vertex = &mut vertex_normals[triangle[i] as usize];
+                *vertex = add(vertex, &scale(normal, angle));
+            }
+        }
+
+        let edge_normals = indices
+            .iter()
+            .map(|triangle| {
+                std::array::from_fn(|i| {
+                    let (start, end) = (triangle[i], triangle[(i + 1) % 3]);
+                    normalize(&shared_edges[&(start.min(end), start.max(end))])
+                })
+            })
+            .collect();
+
+        let vertex_normals = vertex_normals.iter().map(normalize).collect();
+
+        let bounds: Vec<_> = indices
+            .iter()
+            .map(|triangle| {
+                let [a, b, c] = corners(triangle);
+                (
+                    std::array::from_fn(|i| a[i].min(b[i]).min(c[i])),
+                    std::array::from_fn(|i| a[i].max(b[i]).max(c[i])),
+                )
+            })
+            .collect();
+
+        let bvh = Bvh::new(&bounds);
+
+        Self {
+            positions,
+            indices,
+            face_normals,
+            edge_normals,
+            vertex_normals,
+            bvh,
+        }
+    }
+
+    /// Creates the SDF of a mesh, such as one extracted from another SDF or loaded from a file.
+    #[inline]
+    pub fn from_mesh<State>(mesh: &Mesh<Scalar, State>) -> Self {
+        Self::new(mesh.positions.clone(), mesh.indices.clone())
+    }
+
+    #[inline]
+    pub fn positions(&self) -> &[[Scalar; 3]] {
+        &self.positions
+    }
+
+    #[inline]
+    pub fn indices(&self) -> &[[u32; 3]] {
+        &self.indices
+    }
+
+    /// Returns the point of a triangle closest to a given point, and the feature of the triangle
+    /// it lies on. See "Real-Time Collision Detection" by Christer Ericson, section 5.1.5.
+    fn closest_point(&self, triangle: usize, point: &[Scalar; 3]) -> ([Scalar; 3], Feature) {
+        let [a, b, c] = self.indices[triangle].map(|i| self.positions[i as usize]);
+        let zero = Scalar::zero();
+
+        let ab = sub(&b, &a);
+        let ac = sub(&c, &a);
+
+        let ap = sub(point, &a);
+        let d1 = dot(&ab, &ap);
+        let d2 = dot(&ac, &ap);
+        if d1 <= zero && d2 <= zero {
+            return (a, Feature::Vertex(0));
+        }
+
+        let bp = sub(point, &b);
+        let d3 = dot(&ab, &bp);
+        let d4 = dot(&ac, &bp);
+        if d3 >= zero && d4 <= d3 {
+            return (b, Feature::Vertex(1));
+        }
+
+        let vc = d1 * d4 - d3 * d2;
+        if vc <= zero && d1 >= zero && d3 <= zero {
+            let v = d1 / (d1 - d3);
+            return (add(&a, &scale(&ab, v)), Feature::Edge(0));
+        }
+
+        let cp = sub(point, &c);
+        let d5 = dot(&ab, &cp);
+        let d6 = dot(&ac, &cp);
+        if d6 >= zero && d5 <= d6 {
+            return (c, Feature::Vertex(2));
+        }
+
+        let vb = d5 * d2 - d1 * d6;
+        if vb <= zero && d2 >= zero && d6 <= zero {
+            let w = d2 / (d2 - d6);
+            return (add(&a, &scale(&ac, w)), Feature::Edge(2));
+        }
+
+        let va = d3 * d6 - d5 * d4;
+        if va <= zero && d4 - d3 >= zero && d5 - d6 >= zero {
+            let w = (d4 - d3) / ((d4 - d3) + (d5 - d6));
+            return (add(&b, &scale(&sub(&c, &b), w)), Feature::Edge(1));
+        }
+
+        let denominator = Scalar::one() / (va + vb + vc);
+        let v = vb * denominator;
+        let w = vc * denominator;
+
+        (add(&a, &add(&scale(&ab, v), &scale(&ac, w))), Feature::Face)
+    }
+}
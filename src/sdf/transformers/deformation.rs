@@ -0,0 +1,327 @@
+use crate::{Sdf, SdfState};
+use num::Float;
+use std::marker::PhantomData;
+
+/// Twists a 3D SDF around an axis going through the origin, rotating its cross-sections by an
+/// angle in radians per unit length along the axis, counter-clockwise when looking down the axis.
+///
+/// Twisting stretches space more and more away from the axis, so the distance is divided by a
+/// bound of that stretch over the region the returned distance covers. This keeps the distance a
+/// lower bound of the true one, at the cost of shorter steps when marching far from the axis.
+///
+/// This struct should not be used directly, instead it is recommended to use the function
+/// [twist](SdfTwistOperations::twist) defined on any 3D SDFs.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // A column twisted by a quarter turn per unit of height.
+/// let column = box_n([0.5, 2.0, 0.5]).twist(1, std::f32::consts::FRAC_PI_2);
+///
+/// // Halfway up the column, the corners of the square cross-section face the axes.
+/// assert!(column.distance([0.0, 0.5, 0.6]) < 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Twisted<Scalar: Float, T, State: SdfState>
+where
+    T: Sdf<Scalar, 3, State>,
+{
+    inner: T,
+    axis: usize,
+    rate: Scalar,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, T, State: SdfState> Sdf<Scalar, 3, State> for Twisted<Scalar, T, State>
+where
+    T: Sdf<Scalar, 3, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; 3]) -> Scalar {
+        let (local, radius) = self.to_local(point);
+        let distance = self.inner.distance_from_slice(&local);
+
+        // The twist shears space by the rate times the distance to the axis, the largest singular
+        // value of such a shear being `s / 2 + sqrt(1 + s^2 / 4)`.
+        let half_shear = self.rate.abs() * (radius + distance.abs()) / Scalar::from(2.0).unwrap();
+
+        distance / (half_shear + (Scalar::one() + half_shear * half_shear).sqrt())
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; 3]) -> State {
+        self.inner.state(&self.to_local(point).0)
+    }
+}
+
+impl<Scalar: Float, T, State: SdfState> Twisted<Scalar, T, State>
+where
+    T: Sdf<Scalar, 3, State>,
+{
+    #[inline]
+    pub fn new(inner: T, axis: usize, rate: Scalar) -> Self {
+        if axis >= 3 {
+            panic!("Cannot twist an SDF around an axis it does not have");
+        }
+
+        Self {
+            inner,
+            axis,
+            rate,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the point untwisted into the space of the inner SDF, and its distance to the axis.
+    #[inline]
+    fn to_local(&self, point: &[Scalar; 3]) -> ([Scalar; 3], Scalar) {
+        let (u, v) = ((self.axis + 1) % 3, (self.axis + 2) % 3);
+        let (sin, cos) = (self.rate * point[self.axis]).sin_cos();
+
+        let mut local = *point;
+        local[u] = point[u] * cos + point[v] * sin;
+        local[v] = point[v] * cos - point[u] * sin;
+
+        (local, point[u].hypot(point[v]))
+    }
+}
+
+/// Bends an SDF in the plane of two axes, curving the first axis towards the second one by an
+/// angle in radians per unit length along the first axis. This is the cheap bend, which rotates
+/// each slice of the SDF around the origin rather than following an arc.
+///
+/// The distance is divided by a bound of the stretch of space caused by the bend over the region
+/// the returned distance covers, which keeps it a lower bound of the true one.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // A plank bending upwards at both ends.
+/// let plank = box_n([2.0, 0.1, 0.5]).bend([0, 1], 0.25);
+///
+/// assert!(plank.distance([1.5f32, 0.5, 0.0]).abs() < 0.15);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Bent<Scalar: Float, T, const DIM: usize, State: SdfState>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    inner: T,
+    plane: [usize; 2],
+    rate: Scalar,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for Bent<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let (local, radius) = self.to_local(point);
+        let distance = self.inner.distance_from_slice(&local);
+
+        // The bend adds a term of the rate times the distance to the origin in the plane to the
+        // derivative of a rotation.
+        distance / (Scalar::one() + self.rate.abs() * (radius + distance.abs()))
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.inner.state(&self.to_local(point).0)
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Bent<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(inner: T, plane: [usize; 2], rate: Scalar) -> Self {
+        if plane[0] >= DIM || plane[1] >= DIM || plane[0] == plane[1] {
+            panic!("Cannot bend an SDF in a plane it does not have");
+        }
+
+        Self {
+            inner,
+            plane,
+            rate,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the point unbent into the space of the inner SDF, and its distance to the origin in
+    /// the plane of the bend.
+    #[inline]
+    fn to_local(&self, point: &[Scalar; DIM]) -> ([Scalar; DIM], Scalar) {
+        let [u, v] = self.plane;
+        let (sin, cos) = (-self.rate * point[u]).sin_cos();
+
+        let mut local = *point;
+        local[u] = point[u] * cos - point[v] * sin;
+        local[v] = point[u] * sin + point[v] * cos;
+
+        (local, point[u].hypot(point[v]))
+    }
+}
+
+/// The scale below which the cross-sections of a [`Tapered`] SDF are cut off.
+const TAPER_MIN_SCALE: f64 = 1.0 / 32.0;
+
+/// Tapers an SDF along an axis, scaling its cross-sections by `1 + rate * h` where `h` is the
+/// coordinate along the axis. Towards the tip, where the scale would reach 0, the SDF is cut off
+/// by the plane where the scale drops to 1/32, and it is empty past that plane.
+///
+/// The distance is divided by a bound of the stretch of space caused by the taper over the region
+/// the returned distance covers, which keeps it a lower bound of the true one. Past the cut-off
+/// plane, the distance is bounded by the distance to the plane instead.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // A pyramid-like column, twice as wide at the bottom as at the top.
+/// let column = box_n([1.0, 1.0, 1.0]).taper(1, -1.0 / 3.0);
+///
+/// assert!(column.distance([1.2, -0.9, 0.0]) < 0.0);
+/// assert!(column.distance([0.8, 0.9, 0.0]) > 0.0);
+///
+/// // Past the tip of the taper, 2.5 away from the column.
+/// assert!(column.distance([0.0, 3.5, 0.0]) > 0.5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tapered<Scalar: Float, T, const DIM: usize, State: SdfState>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    inner: T,
+    axis: usize,
+    rate: Scalar,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for Tapered<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let scale = self.scale(point);
+        if self.rate == Scalar::zero() {
+            return self.tapered_distance(point, scale);
+        }
+
+        // The signed distance to the cut-off plane, negative on the side that is kept.
+        let min_scale = Scalar::from(TAPER_MIN_SCALE).unwrap();
+        let cutoff = (min_scale - scale) / self.rate.abs();
+
+        if cutoff <= Scalar::zero() {
+            return self.tapered_distance(point, scale).max(cutoff);
+        }
+
+        // Past the plane, the surface is at least as far as the plane, and at least as far as the
+        // bound on the plane minus the distance to it.
+        let mut projected = *point;
+        projected[self.axis] = (min_scale - Scalar::one()) / self.rate;
+
+        cutoff.max(self.tapered_distance(&projected, min_scale) - cutoff)
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        let scale = self
+            .scale(point)
+            .max(Scalar::from(TAPER_MIN_SCALE).unwrap());
+
+        self.inner.state(&self.to_local(point, scale))
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Tapered<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(inner: T, axis: usize, rate: Scalar) -> Self {
+        if axis >= DIM {
+            panic!("Cannot taper an SDF along an axis it does not have");
+        }
+
+        Self {
+            inner,
+            axis,
+            rate,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the scale of the cross-section containing the point.
+    #[inline]
+    fn scale(&self, point: &[Scalar; DIM]) -> Scalar {
+        Scalar::one() + self.rate * point[self.axis]
+    }
+
+    /// Returns a bound of the distance to the tapered SDF, ignoring the cut-off plane. The scale
+    /// must be positive.
+    fn tapered_distance(&self, point: &[Scalar; DIM], scale: Scalar) -> Scalar {
+        let distance = self.inner.distance_from_slice(&self.to_local(point, scale));
+
+        // Only look as far as the scale halves, so that the bound stays finite near the tip.
+        let two = Scalar::from(2.0).unwrap();
+        let rate = self.rate.abs();
+        let reach = if rate > Scalar::zero() {
+            distance.abs().min(scale / (two * rate))
+        } else {
+            distance.abs()
+        };
+        let min_scale = scale - rate * reach;
+
+        // Cross-sections are divided by the scale, and sheared by the rate times the distance to
+        // the axis over the squared scale.
+        let radius = (0..DIM)
+            .filter(|&i| i != self.axis)
+            .fold(Scalar::zero(), |sum, i| sum + point[i] * point[i])
+            .sqrt();
+        let lipschitz = min_scale.recip().max(Scalar::one())
+            + rate * (radius + reach) / (min_scale * min_scale);
+
+        (distance.abs() / lipschitz).min(reach) * distance.signum()
+    }
+
+    #[inline]
+    fn to_local(&self, point: &[Scalar; DIM], scale: Scalar) -> [Scalar; DIM] {
+        std::array::from_fn(|i| {
+            if i == self.axis {
+                point[i]
+            } else {
+                point[i] / scale
+            }
+        })
+    }
+}
+
+pub trait SdfTwistOperations<Scalar: Float, State: SdfState>:
+    Sdf<Scalar, 3, State> + Sized
+{
+    /// Twists the SDF around an axis going through the origin, by an angle in radians per unit
+    /// length along the axis.
+    #[inline]
+    fn twist(self, axis: usize, rate: Scalar) -> Twisted<Scalar, Self, State> {
+        Twisted::new(self, axis, rate)
+    }
+}
+
+impl<T, Scalar: Float, State: SdfState> SdfTwistOperations<Scalar, State> for T where
+    Self: Sdf<Scalar, 3, State> + Sized
+{
+}
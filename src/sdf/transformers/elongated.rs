@@ -0,0 +1,90 @@
+use std::marker::PhantomData;
+
+use crate::{Sdf, SdfState};
+use num::Float;
+
+/// Stretches an SDF by inserting a straight segment of length `2 * h` along each axis at the
+/// origin, the two halves of the SDF being moved apart rather than scaled. Points within the
+/// inserted segment are mapped onto the origin, so a sphere becomes a capsule or a rounded box
+/// without distorting its curvature.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // A capsule of length 4 along the X axis.
+/// let capsule = Sphere.elongate([1.0, 0.0, 0.0]);
+///
+/// assert_eq!(capsule.distance([3.0, 0.0, 0.0]), 1.0);
+/// assert_eq!(capsule.distance([0.5, 2.0, 0.0]), 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(bound(
+        serialize = "Scalar: serde::Serialize, T: serde::Serialize",
+        deserialize = "Scalar: serde::Deserialize<'de>, T: serde::Deserialize<'de>"
+    ))
+)]
+pub struct Elongated<Scalar: Float, T, const DIM: usize, State: SdfState>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    inner: T,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    half_lengths: [Scalar; DIM],
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for Elongated<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        // Inside of the inserted segment along every axis, the inner SDF is evaluated at the
+        // origin, so the distance to the sides of the segment keeps the interior exact.
+        let inside = (0..DIM)
+            .map(|i| point[i].abs() - self.half_lengths[i])
+            .fold(Scalar::neg_infinity(), Scalar::max)
+            .min(Scalar::zero());
+
+        self.inner.distance_from_slice(&self.to_local(point)) + inside
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.inner.state(&self.to_local(point))
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Elongated<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(inner: T, half_lengths: [Scalar; DIM]) -> Self {
+        if half_lengths.iter().any(|h| *h < Scalar::zero()) {
+            panic!("Cannot define an elongation with negative lengths");
+        }
+
+        Self {
+            inner,
+            half_lengths,
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    fn to_local(&self, point: &[Scalar; DIM]) -> [Scalar; DIM] {
+        std::array::from_fn(|i| {
+            point[i]
+                - point[i]
+                    .max(-self.half_lengths[i])
+                    .min(self.half_lengths[i])
+        })
+    }
+}
@@ -0,0 +1,182 @@
+use crate::{
+    Sdf, SdfState,
+    math::{dot, normalize, scale, sub},
+};
+use num::Float;
+use std::marker::PhantomData;
+
+/// Mirrors an SDF across a hyperplane, by folding points from the back of the plane onto its
+/// front before evaluating the inner SDF. Only the part of the inner SDF in front of the plane
+/// is kept, along with its reflection. This is much cheaper than the union of the SDF and a
+/// reflected copy of itself.
+///
+/// The side state function receives the state of the inner SDF and whether the point was folded
+/// from the back of the plane, so that both halves can be told apart.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // Two spheres on both sides of the YZ plane, from a single one.
+/// let pair = Sphere.translate(&[2.0, 0.0, 0.0]).mirror(0);
+///
+/// // Color the mirrored sphere differently.
+/// let colored = Mirrored::new(
+///     Sphere.translate(&[2.0, 0.0, 0.0]),
+///     [1.0, 0.0, 0.0],
+///     0.0,
+///     |_, mirrored| if mirrored { [0.0, 0.0, 1.0] } else { [1.0, 0.0, 0.0] },
+/// );
+///
+/// assert_eq!(pair.distance([-2.0, 0.0, 0.0]), -1.0);
+/// assert_eq!(colored.state(&[-2.0, 0.0, 0.0]), [0.0, 0.0, 1.0]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct Mirrored<Scalar: Float, T, const DIM: usize, InnerState: SdfState, State: SdfState, F>
+where
+    T: Sdf<Scalar, DIM, InnerState>,
+    F: Fn(InnerState, bool) -> State,
+{
+    inner: T,
+    normal: [Scalar; DIM],
+    offset: Scalar,
+    side_state: F,
+    _marker: PhantomData<(InnerState, State)>,
+}
+
+impl<Scalar: Float, T, const DIM: usize, InnerState: SdfState, State: SdfState, F>
+    Sdf<Scalar, DIM, State> for Mirrored<Scalar, T, DIM, InnerState, State, F>
+where
+    T: Sdf<Scalar, DIM, InnerState>,
+    F: Fn(InnerState, bool) -> State,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        self.inner.distance_from_slice(&self.fold(point).0)
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        let (point, mirrored) = self.fold(point);
+
+        (self.side_state)(self.inner.state(&point), mirrored)
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, InnerState: SdfState, State: SdfState, F>
+    Mirrored<Scalar, T, DIM, InnerState, State, F>
+where
+    T: Sdf<Scalar, DIM, InnerState>,
+    F: Fn(InnerState, bool) -> State,
+{
+    /// Mirrors the SDF across the plane of points `p` such that `dot(p, normal) == offset`. The
+    /// normal does not need to be normalized, and points towards the kept side of the plane.
+    #[inline]
+    pub fn new(inner: T, normal: [Scalar; DIM], offset: Scalar, side_state: F) -> Self {
+        let length = dot(&normal, &normal).sqrt();
+        if length == Scalar::zero() {
+            panic!("Cannot define a mirror plane with a null normal");
+        }
+
+        Self {
+            inner,
+            normal: normalize(&normal),
+            offset: offset / length,
+            side_state,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the point folded in front of the plane, and whether it was behind it.
+    #[inline]
+    fn fold(&self, point: &[Scalar; DIM]) -> ([Scalar; DIM], bool) {
+        let distance = dot(point, &self.normal) - self.offset;
+
+        if distance < Scalar::zero() {
+            (sub(point, &scale(&self.normal, distance + distance)), true)
+        } else {
+            (*point, false)
+        }
+    }
+}
+
+/// Mirrors an SDF across several axes at once, folding the selected coordinates onto their
+/// positive side. The side state function receives the state of the inner SDF and, for each
+/// axis, whether the point was folded along it.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // Four spheres in the corners of a square, from a single one.
+/// let corners = Sphere.translate(&[2.0, 0.0, 2.0]).mirror_axes([true, false, true]);
+///
+/// assert_eq!(corners.distance([-2.0, 0.0, -2.0]), -1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct MirroredAxes<
+    Scalar: Float,
+    T,
+    const DIM: usize,
+    InnerState: SdfState,
+    State: SdfState,
+    F,
+> where
+    T: Sdf<Scalar, DIM, InnerState>,
+    F: Fn(InnerState, [bool; DIM]) -> State,
+{
+    inner: T,
+    axes: [bool; DIM],
+    side_state: F,
+    _marker: PhantomData<(Scalar, InnerState, State)>,
+}
+
+impl<Scalar: Float, T, const DIM: usize, InnerState: SdfState, State: SdfState, F>
+    Sdf<Scalar, DIM, State> for MirroredAxes<Scalar, T, DIM, InnerState, State, F>
+where
+    T: Sdf<Scalar, DIM, InnerState>,
+    F: Fn(InnerState, [bool; DIM]) -> State,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        self.inner.distance_from_slice(&self.fold(point).0)
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        let (point, mirrored) = self.fold(point);
+
+        (self.side_state)(self.inner.state(&point), mirrored)
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, InnerState: SdfState, State: SdfState, F>
+    MirroredAxes<Scalar, T, DIM, InnerState, State, F>
+where
+    T: Sdf<Scalar, DIM, InnerState>,
+    F: Fn(InnerState, [bool; DIM]) -> State,
+{
+    #[inline]
+    pub fn new(inner: T, axes: [bool; DIM], side_state: F) -> Self {
+        Self {
+            inner,
+            axes,
+            side_state,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the point folded onto the positive side of the selected axes, and for each axis
+    /// whether it was folded.
+    #[inline]
+    fn fold(&self, point: &[Scalar; DIM]) -> ([Scalar; DIM], [bool; DIM]) {
+        let mirrored = std::array::from_fn(|i| self.axes[i] && point[i] < Scalar::zero());
+
+        (
+            std::array::from_fn(|i| if mirrored[i] { -point[i] } else { point[i] }),
+            mirrored,
+        )
+    }
+}
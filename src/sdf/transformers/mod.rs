@@ -1,24 +1,38 @@
 mod boxed;
+mod deformation;
+mod elongated;
 mod inverted;
+mod mirror;
+mod onion;
 mod operations;
+mod polar;
+mod quaternion;
 mod repetition;
 #[cfg(feature = "glam")]
 mod rotation;
 mod rounding;
 mod scale;
 mod thickened;
+mod transformed;
 mod translation;
 
 pub use boxed::Boxed;
+pub use deformation::{Bent, SdfTwistOperations, Tapered, Twisted};
+pub use elongated::Elongated;
 pub use inverted::Inverted;
+pub use mirror::{Mirrored, MirroredAxes};
+pub use onion::Onion;
 pub use operations::SdfTransformOperations;
-pub use repetition::Repeated;
+pub use polar::{RepeatedPolar, RepeatedSectors, SdfPolar3dOperations, SdfPolarOperations};
+pub use quaternion::{Quaternion, Rotated, SdfRotationOperations};
+pub use repetition::{Repeated, RepeatedCells, RepeatedLimited};
 #[cfg(feature = "glam")]
 pub use rotation::{
     DRotated3d, Rotated2d, Rotated3d, SdfDRotation3dOperations, SdfRotation2dOperations,
     SdfRotation3dOperations,
 };
 pub use rounding::Rounded;
-pub use scale::Scaled;
+pub use scale::{Scaled, ScaledAxes};
 pub use thickened::Thickened;
+pub use transformed::{AffineTransformer, Transformed};
 pub use translation::Translated;
@@ -0,0 +1,124 @@
+use std::marker::PhantomData;
+
+use crate::{Sdf, SdfState};
+use num::Float;
+
+/// Turns an SDF into a number of concentric shells, like nested [`Thickened`](super::Thickened)
+/// SDFs. The outermost shell is centered on the surface of the SDF, and the following ones go
+/// inwards, each shell being `2 * thickness` thick and separated from the next one by a gap of the
+/// same size.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // Three nested spherical shells, of radii 2, 1.6 and 1.2.
+/// let shells = Sphere.scale(2.0).onion(0.1, 3);
+///
+/// assert!((shells.distance([2.0f32, 0.0, 0.0]) + 0.1).abs() < 1e-6);
+/// assert!((shells.distance([1.2f32, 0.0, 0.0]) + 0.1).abs() < 1e-6);
+/// assert!(shells.distance([1.4, 0.0, 0.0]) > 0.0);
+/// assert!(shells.distance([0.0, 0.0, 0.0]) > 0.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(
+    feature = "serde",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(
+        try_from = "RawOnion<Scalar, T>",
+        bound(deserialize = "Scalar: serde::Deserialize<'de>, T: serde::Deserialize<'de>")
+    )
+)]
+pub struct Onion<Scalar: Float, T, const DIM: usize, State: SdfState>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    inner: T,
+    thickness: Scalar,
+    layers: usize,
+    _marker: PhantomData<State>,
+}
+
+/// The fields of an [`Onion`] as they are deserialized, before going through the same checks as
+/// [`Onion::new`].
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct RawOnion<Scalar, T> {
+    inner: T,
+    thickness: Scalar,
+    layers: usize,
+}
+
+#[cfg(feature = "serde")]
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> TryFrom<RawOnion<Scalar, T>>
+    for Onion<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    type Error = &'static str;
+
+    fn try_from(raw: RawOnion<Scalar, T>) -> Result<Self, Self::Error> {
+        Self::validate(raw.layers)?;
+
+        Ok(Self {
+            inner: raw.inner,
+            thickness: raw.thickness,
+            layers: raw.layers,
+            _marker: PhantomData,
+        })
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for Onion<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        let distance = self.inner.distance_from_slice(point);
+
+        // Shells are centered every four thicknesses inwards, only the closest one matters.
+        let spacing = self.thickness * Scalar::from(4.0).unwrap();
+        let layer = (-distance / spacing)
+            .round()
+            .max(Scalar::zero())
+            .min(Scalar::from(self.layers - 1).unwrap());
+
+        (distance + layer * spacing).abs() - self.thickness
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.inner.state(point)
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Onion<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(inner: T, thickness: Scalar, layers: usize) -> Self {
+        if let Err(message) = Self::validate(layers) {
+            panic!("{message}");
+        }
+
+        Self {
+            inner,
+            thickness,
+            layers,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Checks that the onion has at least one layer.
+    fn validate(layers: usize) -> Result<(), &'static str> {
+        if layers == 0 {
+            return Err("Cannot define an onion without any layer");
+        }
+
+        Ok(())
+    }
+}
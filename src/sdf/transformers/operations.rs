@@ -1,7 +1,10 @@
 use crate::{Sdf, SdfState};
 use num::Float;
 
-use super::{Boxed, Inverted, Repeated, Rounded, Scaled, Thickened, Translated};
+use super::{
+    Bent, Boxed, Elongated, Inverted, Mirrored, MirroredAxes, Onion, Repeated, RepeatedCells,
+    RepeatedLimited, Rounded, Scaled, ScaledAxes, Tapered, Thickened, Transformed, Translated,
+};
 
 pub trait SdfTransformOperations<Scalar: Float, const DIM: usize, State: SdfState>:
     Sdf<Scalar, DIM, State> + Sized
@@ -12,19 +15,106 @@ pub trait SdfTransformOperations<Scalar: Float, const DIM: usize, State: SdfStat
         Translated::new(self, translation)
     }
 
-    /// Scales the SDF by a given factor. There is currently no support for non-homogenous scaling.
+    /// Scales the SDF by a given factor. See [`scale_axes`](Self::scale_axes) for non-homogenous
+    /// scaling.
     #[inline]
     fn scale(self, scale: Scalar) -> Scaled<Scalar, Self, DIM, State> {
         Scaled::new(self, scale)
     }
 
+    /// Scales the SDF by a different factor along each axis. The distance is only a lower bound of
+    /// the true distance, which gets worse as the factors get further apart.
+    #[inline]
+    fn scale_axes(self, scale: impl Into<[Scalar; DIM]>) -> ScaledAxes<Scalar, Self, DIM, State> {
+        ScaledAxes::new(self, scale.into())
+    }
+
+    /// Applies an affine transform to the SDF, so that a point `p` of the SDF ends up at
+    /// `matrix * p + translation`. The matrix is given as an array of rows, and must be invertible.
+    #[inline]
+    fn transform(
+        self,
+        matrix: [[Scalar; DIM]; DIM],
+        translation: [Scalar; DIM],
+    ) -> Transformed<Scalar, Self, DIM, State> {
+        Transformed::new(self, matrix, translation)
+    }
+
+    /// Mirrors the SDF across the plane orthogonal to an axis, so that the negative side of the
+    /// axis becomes the reflection of the positive one.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn mirror(
+        self,
+        axis: usize,
+    ) -> Mirrored<Scalar, Self, DIM, State, State, impl Fn(State, bool) -> State> {
+        if axis >= DIM {
+            panic!("Cannot mirror an SDF across an axis it does not have");
+        }
+
+        let normal = std::array::from_fn(|i| {
+            if i == axis {
+                Scalar::one()
+            } else {
+                Scalar::zero()
+            }
+        });
+
+        Mirrored::new(self, normal, Scalar::zero(), |state, _| state)
+    }
+
+    /// Mirrors the SDF across the plane of points `p` such that `dot(p, normal) == offset`, so
+    /// that the back of the plane becomes the reflection of its front, towards which the normal
+    /// points.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn mirror_plane(
+        self,
+        normal: impl Into<[Scalar; DIM]>,
+        offset: Scalar,
+    ) -> Mirrored<Scalar, Self, DIM, State, State, impl Fn(State, bool) -> State> {
+        Mirrored::new(self, normal.into(), offset, |state, _| state)
+    }
+
+    /// Mirrors the SDF across every selected axis at once.
+    #[allow(clippy::type_complexity)]
+    #[inline]
+    fn mirror_axes(
+        self,
+        axes: [bool; DIM],
+    ) -> MirroredAxes<Scalar, Self, DIM, State, State, impl Fn(State, [bool; DIM]) -> State> {
+        MirroredAxes::new(self, axes, |state, _| state)
+    }
+
+    /// Bends the SDF in the plane of two axes, curving the first axis towards the second one by
+    /// an angle in radians per unit length along the first axis.
+    #[inline]
+    fn bend(self, plane: [usize; 2], rate: Scalar) -> Bent<Scalar, Self, DIM, State> {
+        Bent::new(self, plane, rate)
+    }
+
+    /// Tapers the SDF along an axis, scaling its cross-sections by `1 + rate * h` where `h` is the
+    /// coordinate along the axis. The SDF is cut off before the scale reaches 0.
+    #[inline]
+    fn taper(self, axis: usize, rate: Scalar) -> Tapered<Scalar, Self, DIM, State> {
+        Tapered::new(self, axis, rate)
+    }
+
+    /// Stretches the SDF by inserting a straight segment of length `2 * h` along each axis at the
+    /// origin, moving both halves of the SDF apart instead of scaling it.
+    #[inline]
+    fn elongate(self, h: impl Into<[Scalar; DIM]>) -> Elongated<Scalar, Self, DIM, State> {
+        Elongated::new(self, h.into())
+    }
+
     /// Rounds the corners of the SDF by a given factor.
     #[inline]
     fn round(self, factor: Scalar) -> Rounded<Scalar, Self, DIM, State> {
         Rounded::new(self, factor)
     }
 
-    /// Repeats the SDF to infinity based off of a certain repeat size.
+    /// Repeats the SDF to infinity based off of a certain repeat size. Neighbouring cells are
+    /// checked, so the SDF does not need to be symmetric.
     #[inline]
     fn repeat(
         self,
@@ -33,6 +123,35 @@ pub trait SdfTransformOperations<Scalar: Float, const DIM: usize, State: SdfStat
         Repeated::new(self, repeat_spacing.into())
     }
 
+    /// Repeats the SDF over a finite grid of cells, from the minimum cell to the maximum cell
+    /// included. Neighbouring cells are checked, so the SDF does not need to be symmetric.
+    #[inline]
+    fn repeat_limited(
+        self,
+        repeat_spacing: impl Into<[Scalar; DIM]>,
+        min_cell: [i64; DIM],
+        max_cell: [i64; DIM],
+    ) -> RepeatedLimited<Scalar, Self, DIM, State> {
+        RepeatedLimited::new(self, repeat_spacing.into(), min_cell, max_cell)
+    }
+
+    /// Repeats the SDF over a finite grid of cells, building the SDF of each cell from this SDF and
+    /// the integer index of the cell, so that each instance can vary its shape or state.
+    #[inline]
+    fn repeat_cells<NewState: SdfState, U, F>(
+        self,
+        repeat_spacing: impl Into<[Scalar; DIM]>,
+        min_cell: [i64; DIM],
+        max_cell: [i64; DIM],
+        cell_sdf: F,
+    ) -> RepeatedCells<Scalar, Self, DIM, State, NewState, U, F>
+    where
+        U: Sdf<Scalar, DIM, NewState>,
+        F: Fn(&Self, [i64; DIM]) -> U,
+    {
+        RepeatedCells::new(self, repeat_spacing.into(), min_cell, max_cell, cell_sdf)
+    }
+
     /// Adds thickness to the SDF. Points who's distance to the surface is less than the thickness
     /// value will be inside, others outside.
     #[inline]
@@ -40,6 +159,13 @@ pub trait SdfTransformOperations<Scalar: Float, const DIM: usize, State: SdfStat
         Thickened::new(self, thickness)
     }
 
+    /// Turns the SDF into concentric shells, the outermost one centered on its surface. Each shell
+    /// is `2 * thickness` thick and separated from the next one by a gap of the same size.
+    #[inline]
+    fn onion(self, thickness: Scalar, layers: usize) -> Onion<Scalar, Self, DIM, State> {
+        Onion::new(self, thickness, layers)
+    }
+
     /// Inverts the SDF. What was previously outside is now inside, and vice versa.
     #[inline]
     fn invert(self) -> Inverted<Scalar, Self, DIM, State> {
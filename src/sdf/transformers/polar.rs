@@ -0,0 +1,314 @@
+use crate::{Sdf, SdfState};
+use num::Float;
+use std::marker::PhantomData;
+
+/// Repeats an SDF around the origin into a number of angular sectors, sector 0 being centered on
+/// the first axis of the rotation plane. The inner SDF is evaluated in the sector containing the
+/// point and in its neighbour towards the point, so shapes that are not symmetric within their
+/// sector do not produce distance discontinuities.
+///
+/// This struct should not be used directly, instead it is recommended to use the functions
+/// [repeat_polar](SdfPolarOperations::repeat_polar) defined on any 2D SDFs and
+/// [repeat_polar_3d](SdfPolar3dOperations::repeat_polar_3d) defined on any 3D SDFs.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // The teeth of a gear, as 12 boxes around a circle.
+/// let teeth = box_n([0.2, 0.1]).translate(&[1.0, 0.0]).repeat_polar(12);
+///
+/// // 8 columns around the Y axis.
+/// let columns = Cylinder::new(0.25, 3.0).translate(&[5.0, 0.0, 0.0]).repeat_polar_3d(8, 1);
+///
+/// assert!(teeth.distance([0.0, 1.0]) < 0.0);
+/// assert!(columns.distance([5.0, 0.0, 0.0]) < 0.0);
+///
+/// // Halfway between two columns.
+/// let angle = std::f32::consts::PI / 8.0;
+/// assert!(columns.distance([5.0 * angle.cos(), 0.0, 5.0 * angle.sin()]) > 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct RepeatedPolar<Scalar: Float, T, const DIM: usize, State: SdfState>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    inner: T,
+    sectors: PolarSectors<Scalar, DIM>,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for RepeatedPolar<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        self.sectors
+            .closest(point, |_, local| self.inner.distance_from_slice(local))
+            .2
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        let (_, local, _) = self
+            .sectors
+            .closest(point, |_, local| self.inner.distance_from_slice(local));
+
+        self.inner.state(&local)
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> RepeatedPolar<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    /// Repeats the SDF into `sectors` sectors in the plane of the two given axes, rotating from
+    /// the first one towards the second one.
+    #[inline]
+    pub fn new(inner: T, sectors: usize, plane: [usize; 2]) -> Self {
+        Self {
+            inner,
+            sectors: PolarSectors::new(sectors, plane),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Repeats an SDF into angular sectors like [`RepeatedPolar`], building the SDF of each sector
+/// from its index so that every instance can vary its shape or state. Sectors are numbered from 0
+/// to `sectors - 1`, rotating from the first axis of the plane towards the second one.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // The spokes of a wheel, one of them highlighted.
+/// let spokes = box_n([1.0, 0.05]).translate(&[1.0, 0.0]).repeat_polar_sectors(6, |spoke, sector| {
+///     (*spoke).bind(if sector == 0 { [1.0, 0.0, 0.0] } else { [0.5, 0.5, 0.5] })
+/// });
+///
+/// assert_eq!(spokes.state(&[1.0, 0.0]), [1.0, 0.0, 0.0]);
+/// assert_eq!(spokes.state(&[-1.0, 0.0]), [0.5, 0.5, 0.5]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct RepeatedSectors<
+    Scalar: Float,
+    T,
+    const DIM: usize,
+    InnerState: SdfState,
+    State: SdfState,
+    U,
+    F,
+> where
+    T: Sdf<Scalar, DIM, InnerState>,
+    U: Sdf<Scalar, DIM, State>,
+    F: Fn(&T, usize) -> U,
+{
+    inner: T,
+    sectors: PolarSectors<Scalar, DIM>,
+    sector_sdf: F,
+    _marker: PhantomData<(InnerState, State)>,
+}
+
+impl<Scalar: Float, T, const DIM: usize, InnerState: SdfState, State: SdfState, U, F>
+    Sdf<Scalar, DIM, State> for RepeatedSectors<Scalar, T, DIM, InnerState, State, U, F>
+where
+    T: Sdf<Scalar, DIM, InnerState>,
+    U: Sdf<Scalar, DIM, State>,
+    F: Fn(&T, usize) -> U,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        self.sectors
+            .closest(point, |sector, local| {
+                (self.sector_sdf)(&self.inner, sector).distance_from_slice(local)
+            })
+            .2
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        let (sector, local, _) = self.sectors.closest(point, |sector, local| {
+            (self.sector_sdf)(&self.inner, sector).distance_from_slice(local)
+        });
+
+        (self.sector_sdf)(&self.inner, sector).state(&local)
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, InnerState: SdfState, State: SdfState, U, F>
+    RepeatedSectors<Scalar, T, DIM, InnerState, State, U, F>
+where
+    T: Sdf<Scalar, DIM, InnerState>,
+    U: Sdf<Scalar, DIM, State>,
+    F: Fn(&T, usize) -> U,
+{
+    /// Repeats the SDF into `sectors` sectors in the plane of the two given axes, rotating from
+    /// the first one towards the second one.
+    #[inline]
+    pub fn new(inner: T, sectors: usize, plane: [usize; 2], sector_sdf: F) -> Self {
+        Self {
+            inner,
+            sectors: PolarSectors::new(sectors, plane),
+            sector_sdf,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// The angular sectors shared by the polar repetitions.
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+struct PolarSectors<Scalar: Float, const DIM: usize> {
+    count: usize,
+    plane: [usize; 2],
+    angle: Scalar,
+}
+
+impl<Scalar: Float, const DIM: usize> PolarSectors<Scalar, DIM> {
+    #[inline]
+    fn new(count: usize, plane: [usize; 2]) -> Self {
+        if count == 0 {
+            panic!("Cannot define a polar repetition without any sector");
+        }
+
+        if plane[0] >= DIM || plane[1] >= DIM || plane[0] == plane[1] {
+            panic!("Cannot define a polar repetition in a plane the SDF does not have");
+        }
+
+        Self {
+            count,
+            plane,
+            angle: Scalar::from(std::f64::consts::TAU).unwrap() / Scalar::from(count).unwrap(),
+        }
+    }
+
+    /// Returns the point rotated back from a sector into sector 0.
+    #[inline]
+    fn local(&self, point: &[Scalar; DIM], sector: usize) -> [Scalar; DIM] {
+        let [u, v] = self.plane;
+        let (sin, cos) = (self.angle * Scalar::from(sector).unwrap()).sin_cos();
+
+        let mut local = *point;
+        local[u] = point[u] * cos + point[v] * sin;
+        local[v] = point[v] * cos - point[u] * sin;
+
+        local
+    }
+
+    /// Evaluates the distance in the sector containing the point and in its neighbour towards the
+    /// point, and returns the index of the closest sector, the point rotated back from that
+    /// sector, and its distance.
+    fn closest(
+        &self,
+        point: &[Scalar; DIM],
+        mut distance: impl FnMut(usize, &[Scalar; DIM]) -> Scalar,
+    ) -> (usize, [Scalar; DIM], Scalar) {
+        let [u, v] = self.plane;
+        // The angle lies in [-pi, pi], so the rounded sector lies in [-count / 2, count / 2].
+        let sector = (point[v].atan2(point[u]) / self.angle)
+            .round()
+            .to_i64()
+            .unwrap_or(0)
+            .rem_euclid(self.count as i64) as usize;
+
+        let local = self.local(point, sector);
+        let sector_distance = distance(sector, &local);
+
+        if self.count == 1 {
+            return (sector, local, sector_distance);
+        }
+
+        let neighbour = if local[v] < Scalar::zero() {
+            (sector + self.count - 1) % self.count
+        } else {
+            (sector + 1) % self.count
+        };
+
+        let neighbour_local = self.local(point, neighbour);
+        let neighbour_distance = distance(neighbour, &neighbour_local);
+
+        if neighbour_distance < sector_distance {
+            (neighbour, neighbour_local, neighbour_distance)
+        } else {
+            (sector, local, sector_distance)
+        }
+    }
+}
+
+pub trait SdfPolarOperations<Scalar: Float, State: SdfState>:
+    Sdf<Scalar, 2, State> + Sized
+{
+    /// Repeats the SDF into a number of angular sectors around the origin.
+    #[inline]
+    fn repeat_polar(self, sectors: usize) -> RepeatedPolar<Scalar, Self, 2, State> {
+        RepeatedPolar::new(self, sectors, [0, 1])
+    }
+
+    /// Repeats the SDF into a number of angular sectors around the origin, building the SDF of
+    /// each sector from this SDF and the index of the sector.
+    #[inline]
+    fn repeat_polar_sectors<NewState: SdfState, U, F>(
+        self,
+        sectors: usize,
+        sector_sdf: F,
+    ) -> RepeatedSectors<Scalar, Self, 2, State, NewState, U, F>
+    where
+        U: Sdf<Scalar, 2, NewState>,
+        F: Fn(&Self, usize) -> U,
+    {
+        RepeatedSectors::new(self, sectors, [0, 1], sector_sdf)
+    }
+}
+
+impl<T, Scalar: Float, State: SdfState> SdfPolarOperations<Scalar, State> for T where
+    Self: Sdf<Scalar, 2, State> + Sized
+{
+}
+
+pub trait SdfPolar3dOperations<Scalar: Float, State: SdfState>:
+    Sdf<Scalar, 3, State> + Sized
+{
+    /// Repeats the SDF into a number of angular sectors around an axis going through the origin.
+    /// Sector 0 is centered on the first of the two other axes, so on the X axis when repeating
+    /// around the Y or Z axis.
+    #[inline]
+    fn repeat_polar_3d(self, sectors: usize, axis: usize) -> RepeatedPolar<Scalar, Self, 3, State> {
+        RepeatedPolar::new(self, sectors, polar_plane(axis))
+    }
+
+    /// Repeats the SDF into a number of angular sectors around an axis going through the origin,
+    /// building the SDF of each sector from this SDF and the index of the sector.
+    #[inline]
+    fn repeat_polar_sectors_3d<NewState: SdfState, U, F>(
+        self,
+        sectors: usize,
+        axis: usize,
+        sector_sdf: F,
+    ) -> RepeatedSectors<Scalar, Self, 3, State, NewState, U, F>
+    where
+        U: Sdf<Scalar, 3, NewState>,
+        F: Fn(&Self, usize) -> U,
+    {
+        RepeatedSectors::new(self, sectors, polar_plane(axis), sector_sdf)
+    }
+}
+
+impl<T, Scalar: Float, State: SdfState> SdfPolar3dOperations<Scalar, State> for T where
+    Self: Sdf<Scalar, 3, State> + Sized
+{
+}
+
+/// Returns the two axes of the plane orthogonal to a 3D axis, in increasing order.
+#[inline]
+fn polar_plane(axis: usize) -> [usize; 2] {
+    match axis {
+        0 => [1, 2],
+        1 => [0, 2],
+        2 => [0, 1],
+        _ => panic!("Cannot repeat an SDF around an axis it does not have"),
+    }
+}
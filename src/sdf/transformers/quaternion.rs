@@ -0,0 +1,261 @@
+use std::{marker::PhantomData, ops::Mul};
+
+use crate::{
+    Sdf, SdfState,
+    math::{cross, dot, mul_vector, normalize, scale},
+};
+use num::Float;
+
+/// A unit quaternion describing a 3D rotation, generic over the scalar type so that 3D rotations
+/// do not depend on glam. When the `glam` feature is enabled, it converts from and to glam's
+/// `Quat` and `DQuat`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Quaternion<Scalar: Float> {
+    pub x: Scalar,
+    pub y: Scalar,
+    pub z: Scalar,
+    pub w: Scalar,
+}
+
+impl<Scalar: Float> Default for Quaternion<Scalar> {
+    #[inline]
+    fn default() -> Self {
+        Self::identity()
+    }
+}
+
+impl<Scalar: Float> Quaternion<Scalar> {
+    #[inline]
+    pub fn identity() -> Self {
+        Self {
+            x: Scalar::zero(),
+            y: Scalar::zero(),
+            z: Scalar::zero(),
+            w: Scalar::one(),
+        }
+    }
+
+    /// Creates a rotation of an angle in radians around an axis, which does not need to be
+    /// normalized.
+    #[inline]
+    pub fn from_axis_angle(axis: [Scalar; 3], angle: Scalar) -> Self {
+        let half_angle = angle * Scalar::from(0.5).unwrap();
+        let [x, y, z] = scale(&normalize(&axis), half_angle.sin());
+
+        Self {
+            x,
+            y,
+            z,
+            w: half_angle.cos(),
+        }
+    }
+
+    /// Creates a rotation from Euler angles in radians, rotating around the X axis first, then
+    /// around the Y axis and finally around the Z axis, all of them being fixed world axes.
+    #[inline]
+    pub fn from_euler(x: Scalar, y: Scalar, z: Scalar) -> Self {
+        let zero = Scalar::zero();
+        let one = Scalar::one();
+
+        Self::from_axis_angle([zero, zero, one], z)
+            * Self::from_axis_angle([zero, one, zero], y)
+            * Self::from_axis_angle([one, zero, zero], x)
+    }
+
+    /// Returns the rotation with the same axis and the opposite angle.
+    #[inline]
+    pub fn inverse(&self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+            w: self.w,
+        }
+    }
+
+    /// Scales the quaternion back to a unit length, which compensates for the drift of long
+    /// chains of multiplications.
+    #[inline]
+    pub fn normalize(&self) -> Self {
+        let [x, y, z, w] = normalize(&[self.x, self.y, self.z, self.w]);
+
+        Self { x, y, z, w }
+    }
+
+    #[inline]
+    pub fn rotate(&self, vector: &[Scalar; 3]) -> [Scalar; 3] {
+        mul_vector(&self.to_matrix(), vector)
+    }
+
+    /// Returns the rotation matrix of the quaternion, as an array of rows.
+    pub fn to_matrix(&self) -> [[Scalar; 3]; 3] {
+        let one = Scalar::one();
+        let two = Scalar::from(2.0).unwrap();
+        let Self { x, y, z, w } = *self;
+
+        [
+            [
+                one - two * (y * y + z * z),
+                two * (x * y - w * z),
+                two * (x * z + w * y),
+            ],
+            [
+                two * (x * y + w * z),
+                one - two * (x * x + z * z),
+                two * (y * z - w * x),
+            ],
+            [
+                two * (x * z - w * y),
+                two * (y * z + w * x),
+                one - two * (x * x + y * y),
+            ],
+        ]
+    }
+}
+
+impl<Scalar: Float> Mul for Quaternion<Scalar> {
+    type Output = Self;
+
+    /// Composes both rotations, applying the right hand side first.
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let lhs_vector = [self.x, self.y, self.z];
+        let rhs_vector = [rhs.x, rhs.y, rhs.z];
+
+        let [x, y, z] = std::array::from_fn(|i| {
+            self.w * rhs_vector[i] + rhs.w * lhs_vector[i] + cross(&lhs_vector, &rhs_vector)[i]
+        });
+
+        Self {
+            x,
+            y,
+            z,
+            w: self.w * rhs.w - dot(&lhs_vector, &rhs_vector),
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::Quat> for Quaternion<f32> {
+    #[inline]
+    fn from(quat: glam::Quat) -> Self {
+        Self {
+            x: quat.x,
+            y: quat.y,
+            z: quat.z,
+            w: quat.w,
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Quaternion<f32>> for glam::Quat {
+    #[inline]
+    fn from(quaternion: Quaternion<f32>) -> Self {
+        glam::Quat::from_xyzw(quaternion.x, quaternion.y, quaternion.z, quaternion.w)
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<glam::DQuat> for Quaternion<f64> {
+    #[inline]
+    fn from(quat: glam::DQuat) -> Self {
+        Self {
+            x: quat.x,
+            y: quat.y,
+            z: quat.z,
+            w: quat.w,
+        }
+    }
+}
+
+#[cfg(feature = "glam")]
+impl From<Quaternion<f64>> for glam::DQuat {
+    #[inline]
+    fn from(quaternion: Quaternion<f64>) -> Self {
+        glam::DQuat::from_xyzw(quaternion.x, quaternion.y, quaternion.z, quaternion.w)
+    }
+}
+
+/// Rotates a 3D SDF by a [`Quaternion`], for any scalar type and without requiring glam. The
+/// inverse rotation is stored as a matrix, which is cheaper to apply than the quaternion itself.
+/// This struct should not be used directly, instead it is recommended to use the function
+/// [rotate_3d](crate::sdf::transformers::SdfRotationOperations::rotate_3d) defined on any 3D SDFs.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // A cube rotated by 45 degrees around the Y axis.
+/// let cube = Cube.rotate_3d(Quaternion::from_axis_angle([0.0, 1.0, 0.0], 45f64.to_radians()));
+///
+/// // A torus standing up, from Euler angles.
+/// let torus = Torus::new(1.0, 0.25).rotate_3d(Quaternion::from_euler(90f32.to_radians(), 0.0, 0.0));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rotated<Scalar: Float, T, State: SdfState>
+where
+    T: Sdf<Scalar, 3, State>,
+{
+    pub(super) inner: T,
+    pub(super) rotation: Quaternion<Scalar>,
+    inverse_matrix: [[Scalar; 3]; 3],
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, T, State: SdfState> Sdf<Scalar, 3, State> for Rotated<Scalar, T, State>
+where
+    T: Sdf<Scalar, 3, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; 3]) -> Scalar {
+        self.inner
+            .distance_from_slice(&mul_vector(&self.inverse_matrix, point))
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; 3]) -> State {
+        self.inner.state(&mul_vector(&self.inverse_matrix, point))
+    }
+}
+
+impl<Scalar: Float, T, State: SdfState> Rotated<Scalar, T, State>
+where
+    T: Sdf<Scalar, 3, State>,
+{
+    #[inline]
+    pub fn new(inner: T, rotation: Quaternion<Scalar>) -> Self {
+        let rotation = rotation.normalize();
+
+        Self {
+            inner,
+            rotation,
+            inverse_matrix: rotation.inverse().to_matrix(),
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn rotation(&self) -> Quaternion<Scalar> {
+        self.rotation
+    }
+}
+
+pub trait SdfRotationOperations<Scalar: Float, State: SdfState>:
+    Sdf<Scalar, 3, State> + Sized
+{
+    /// Rotates the SDF by a quaternion, which can also be a glam `Quat` or `DQuat` when the `glam`
+    /// feature is enabled.
+    #[inline]
+    fn rotate_3d(self, rotation: impl Into<Quaternion<Scalar>>) -> Rotated<Scalar, Self, State> {
+        Rotated::new(self, rotation.into())
+    }
+}
+
+impl<T, Scalar: Float, State: SdfState> SdfRotationOperations<Scalar, State> for T where
+    Self: Sdf<Scalar, 3, State> + Sized
+{
+}
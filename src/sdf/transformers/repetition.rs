@@ -2,13 +2,17 @@ use crate::{Sdf, SdfState};
 use num::Float;
 use std::{array, marker::PhantomData};
 
+/// Repeats an SDF to infinity, cell `[0, 0, ...]` being centered at the origin. Like
+/// [`RepeatedLimited`], the neighbouring cells towards the point are evaluated as well, so inner
+/// SDFs that are not symmetric or that overflow their cell do not produce distance
+/// discontinuities.
 #[derive(Debug, Clone, Copy, PartialEq, Hash)]
 pub struct Repeated<Scalar: Float, T, const DIM: usize, State: SdfState>
 where
     T: Sdf<Scalar, DIM, State>,
 {
     inner: T,
-    repeat_spacing: [Scalar; DIM],
+    cells: CellGrid<Scalar, DIM>,
     _marker: PhantomData<State>,
 }
 
@@ -19,14 +23,18 @@ where
 {
     #[inline]
     fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
-        self.inner.distance_from_slice(&array::from_fn(|i| {
-            point[i] - self.repeat_spacing[i] * (point[i] / self.repeat_spacing[i]).round()
-        }))
+        self.cells
+            .closest(point, |_, local| self.inner.distance_from_slice(local))
+            .2
     }
 
     #[inline]
     fn state(&self, point: &[Scalar; DIM]) -> State {
-        self.inner.state(point)
+        let (_, local, _) = self
+            .cells
+            .closest(point, |_, local| self.inner.distance_from_slice(local));
+
+        self.inner.state(&local)
     }
 }
 
@@ -38,8 +46,256 @@ where
     pub fn new(inner: T, repeat_spacing: [Scalar; DIM]) -> Self {
         Self {
             inner,
-            repeat_spacing,
+            cells: CellGrid::new(repeat_spacing, [i64::MIN; DIM], [i64::MAX; DIM]),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Repeats an SDF over a finite grid of cells, from the minimum cell to the maximum cell included,
+/// cell `[0, 0, ...]` being centered at the origin. Unlike [`Repeated`], the neighbouring cells
+/// towards the point are evaluated as well, so inner SDFs that are not symmetric or that overflow
+/// their cell do not produce distance discontinuities. This costs up to 2^DIM evaluations of the
+/// inner SDF.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // A row of 5 spheres along the X axis.
+/// let row = Sphere.repeat_limited([3.0, 1.0, 1.0], [-2, 0, 0], [2, 0, 0]);
+///
+/// assert_eq!(row.distance([6.0, 0.0, 0.0]), -1.0);
+/// assert_eq!(row.distance([11.0, 0.0, 0.0]), 4.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct RepeatedLimited<Scalar: Float, T, const DIM: usize, State: SdfState>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    inner: T,
+    cells: CellGrid<Scalar, DIM>,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for RepeatedLimited<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        self.cells
+            .closest(point, |_, local| self.inner.distance_from_slice(local))
+            .2
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        let (_, local, _) = self
+            .cells
+            .closest(point, |_, local| self.inner.distance_from_slice(local));
+
+        self.inner.state(&local)
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> RepeatedLimited<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(
+        inner: T,
+        repeat_spacing: [Scalar; DIM],
+        min_cell: [i64; DIM],
+        max_cell: [i64; DIM],
+    ) -> Self {
+        Self {
+            inner,
+            cells: CellGrid::new(repeat_spacing, min_cell, max_cell),
             _marker: PhantomData,
         }
     }
 }
+
+/// Repeats an SDF over a finite grid of cells like [`RepeatedLimited`], building the SDF of each
+/// cell from its integer index so that every instance can vary its shape or state. Pass
+/// `i64::MIN` and `i64::MAX` as the bounds of an axis to repeat it to infinity.
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // A wall of bricks, alternating their color.
+/// let wall = box_n([0.45, 0.2, 0.2]).repeat_cells(
+///     [1.0, 0.5, 1.0],
+///     [i64::MIN, 0, 0],
+///     [i64::MAX, 9, 0],
+///     |brick, [x, y, _]| (*brick).bind(if (x + y) % 2 == 0 { [0.7, 0.2, 0.1] } else { [0.5, 0.1, 0.1] }),
+/// );
+///
+/// assert_eq!(wall.state(&[3.0, 0.5, 0.0]), [0.7, 0.2, 0.1]);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct RepeatedCells<
+    Scalar: Float,
+    T,
+    const DIM: usize,
+    InnerState: SdfState,
+    State: SdfState,
+    U,
+    F,
+> where
+    T: Sdf<Scalar, DIM, InnerState>,
+    U: Sdf<Scalar, DIM, State>,
+    F: Fn(&T, [i64; DIM]) -> U,
+{
+    inner: T,
+    cells: CellGrid<Scalar, DIM>,
+    cell_sdf: F,
+    _marker: PhantomData<(InnerState, State)>,
+}
+
+impl<Scalar: Float, T, const DIM: usize, InnerState: SdfState, State: SdfState, U, F>
+    Sdf<Scalar, DIM, State> for RepeatedCells<Scalar, T, DIM, InnerState, State, U, F>
+where
+    T: Sdf<Scalar, DIM, InnerState>,
+    U: Sdf<Scalar, DIM, State>,
+    F: Fn(&T, [i64; DIM]) -> U,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        self.cells
+            .closest(point, |cell, local| {
+                (self.cell_sdf)(&self.inner, *cell).distance_from_slice(local)
+            })
+            .2
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        let (cell, local, _) = self.cells.closest(point, |cell, local| {
+            (self.cell_sdf)(&self.inner, *cell).distance_from_slice(local)
+        });
+
+        (self.cell_sdf)(&self.inner, cell).state(&local)
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, InnerState: SdfState, State: SdfState, U, F>
+    RepeatedCells<Scalar, T, DIM, InnerState, State, U, F>
+where
+    T: Sdf<Scalar, DIM, InnerState>,
+    U: Sdf<Scalar, DIM, State>,
+    F: Fn(&T, [i64; DIM]) -> U,
+{
+    #[inline]
+    pub fn new(
+        inner: T,
+        repeat_spacing: [Scalar; DIM],
+        min_cell: [i64; DIM],
+        max_cell: [i64; DIM],
+        cell_sdf: F,
+    ) -> Self {
+        Self {
+            inner,
+            cells: CellGrid::new(repeat_spacing, min_cell, max_cell),
+            cell_sdf,
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// The bounded grid of cells shared by the limited repetitions.
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+struct CellGrid<Scalar: Float, const DIM: usize> {
+    spacing: [Scalar; DIM],
+    min_cell: [i64; DIM],
+    max_cell: [i64; DIM],
+}
+
+impl<Scalar: Float, const DIM: usize> CellGrid<Scalar, DIM> {
+    #[inline]
+    fn new(spacing: [Scalar; DIM], min_cell: [i64; DIM], max_cell: [i64; DIM]) -> Self {
+        if min_cell
+            .iter()
+            .zip(max_cell.iter())
+            .any(|(min, max)| min > max)
+        {
+            panic!(
+                "Cannot define a limited repetition whose minimum cell is above its maximum cell"
+            );
+        }
+
+        Self {
+            spacing,
+            min_cell,
+            max_cell,
+        }
+    }
+
+    /// Evaluates the distance in the cell closest to the point and in its neighbours towards the
+    /// point, and returns the index of the closest cell, the point relative to the center of that
+    /// cell, and its distance.
+    fn closest(
+        &self,
+        point: &[Scalar; DIM],
+        mut distance: impl FnMut(&[i64; DIM], &[Scalar; DIM]) -> Scalar,
+    ) -> ([i64; DIM], [Scalar; DIM], Scalar) {
+        // Clamp in floating point first, as the rounded point may not fit in an integer.
+        let base: [i64; DIM] = array::from_fn(|i| {
+            (point[i] / self.spacing[i])
+                .round()
+                .max(Scalar::from(self.min_cell[i]).unwrap())
+                .min(Scalar::from(self.max_cell[i]).unwrap())
+                .to_i64()
+                .unwrap_or(0)
+        });
+
+        let local = |cell: &[i64; DIM]| -> [Scalar; DIM] {
+            array::from_fn(|i| point[i] - self.spacing[i] * Scalar::from(cell[i]).unwrap())
+        };
+        let base_local = local(&base);
+
+        // The neighbour along each axis is the one on the side of the point within its cell.
+        let neighbour: [i64; DIM] = array::from_fn(|i| {
+            let step = if base_local[i] < Scalar::zero() {
+                -1
+            } else {
+                1
+            };
+
+            base[i]
+                .saturating_add(step)
+                .clamp(self.min_cell[i], self.max_cell[i])
+        });
+
+        let mut closest = (base, base_local, Scalar::infinity());
+
+        for mask in 0..1usize << DIM {
+            // Skip the neighbours that were clamped back onto the base cell along an axis.
+            if (0..DIM).any(|i| mask & (1 << i) != 0 && neighbour[i] == base[i]) {
+                continue;
+            }
+
+            let cell = array::from_fn(|i| {
+                if mask & (1 << i) != 0 {
+                    neighbour[i]
+                } else {
+                    base[i]
+                }
+            });
+            let local = local(&cell);
+            let cell_distance = distance(&cell, &local);
+
+            if cell_distance < closest.2 {
+                closest = (cell, local, cell_distance);
+            }
+        }
+
+        closest
+    }
+}
@@ -23,7 +23,7 @@ pub struct Rotated2d<Scalar: Float, T, State: SdfState>
 where
     T: Sdf<Scalar, 2, State>,
 {
-    inner: T,
+    pub(super) inner: T,
 
     // NOTE: We Store Sine and Cosine instead of the angle to only perform the trigonometry once instead
     // of on each call to distance. This should SIGNIFICANTLY improve performance, as the call to
@@ -35,8 +35,8 @@ where
     //
     // TODO: This optimisation is done assuming other operating systems use a similar convention.
     // We should further investigate on Linux and Windows and on different CPU architectures.
-    sin: Scalar,
-    cos: Scalar,
+    pub(super) sin: Scalar,
+    pub(super) cos: Scalar,
     _marker: PhantomData<State>,
 }
 
@@ -79,8 +79,8 @@ where
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Rotated3d<T: Sdf<f32, 3, State>, State: SdfState> {
-    inner: T,
-    inverse_rotation: Quat,
+    pub(super) inner: T,
+    pub(super) inverse_rotation: Quat,
     _marker: PhantomData<State>,
 }
 
@@ -110,8 +110,8 @@ impl<T: Sdf<f32, 3, State>, State: SdfState> Rotated3d<T, State> {
 
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct DRotated3d<T: Sdf<f64, 3, State>, State: SdfState> {
-    inner: T,
-    inverse_rotation: DQuat,
+    pub(super) inner: T,
+    pub(super) inverse_rotation: DQuat,
     _marker: PhantomData<State>,
 }
 
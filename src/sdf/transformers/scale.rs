@@ -8,8 +8,8 @@ pub struct Scaled<Scalar: Float, T, const DIM: usize, State: SdfState>
 where
     T: Sdf<Scalar, DIM, State>,
 {
-    inner: T,
-    scale: Scalar,
+    pub(super) inner: T,
+    pub(super) scale: Scalar,
     _marker: PhantomData<State>,
 }
 
@@ -45,3 +45,64 @@ where
         }
     }
 }
+
+/// Scales an SDF independently along each axis. As the distances are stretched unevenly, the
+/// distance is multiplied by the smallest scale factor, which keeps it a lower bound of the true
+/// distance so that marching never overshoots the surface.
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScaledAxes<Scalar: Float, T, const DIM: usize, State: SdfState>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    pub(super) inner: T,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_array"))]
+    pub(super) inverse_scale: [Scalar; DIM],
+    min_scale: Scalar,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for ScaledAxes<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        self.inner
+            .distance_from_slice(&array::from_fn(|i| point[i] * self.inverse_scale[i]))
+            * self.min_scale
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.inner
+            .state(&array::from_fn(|i| point[i] * self.inverse_scale[i]))
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> ScaledAxes<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    pub fn new(inner: T, scale: [Scalar; DIM]) -> Self {
+        if scale.iter().any(|&axis| axis == Scalar::zero()) {
+            panic!("Cannot scale an SDF by a factor of 0 along an axis");
+        }
+
+        Self {
+            inner,
+            inverse_scale: scale.map(|axis| Scalar::one() / axis),
+            min_scale: scale
+                .iter()
+                .fold(Scalar::infinity(), |acc, e| acc.min(e.abs())),
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn scale(&self) -> [Scalar; DIM] {
+        self.inverse_scale.map(|axis| Scalar::one() / axis)
+    }
+}
@@ -0,0 +1,294 @@
+use crate::{
+    Sdf, SdfState,
+    math::{add, identity, invert, min_singular_value, mul_matrix, mul_vector, scale},
+};
+use num::Float;
+use std::marker::PhantomData;
+
+#[cfg(feature = "glam")]
+use super::{DRotated3d, Rotated2d, Rotated3d};
+use super::{Quaternion, Rotated, Scaled, ScaledAxes, Translated};
+
+/// Applies an affine transform to an SDF in a single pass. Points are mapped back into the space
+/// of the inner SDF with the precomputed inverse of the transform, and the distance is multiplied
+/// by the smallest singular value of the matrix, which keeps it a lower bound of the true distance
+/// when the transform shears or scales unevenly.
+///
+/// Calling `translate`, `scale`, `scale_axes`, `rotate_3d` or `then` on a `Transformed` SDF
+/// composes the transforms instead of nesting them, and chains of [`AffineTransformer`]s can be
+/// collapsed with [`collapse`](Self::collapse).
+///
+/// # Example:
+///
+/// ```rust
+/// use lightwalk::prelude::*;
+///
+/// // A sheared cube.
+/// let sheared = Cube.transform([[1.0, 0.5, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]], [0.0; 3]);
+///
+/// // Collapse a chain of transformers into a single transform of the sphere.
+/// let chain = Sphere.scale(2.0).translate(&[1.0, 0.0, 0.0]).scale(0.5);
+/// let collapsed = chain.into_transformed().collapse().collapse();
+///
+/// assert_eq!(chain.distance([4.0, 0.0, 0.0]), collapsed.distance([4.0, 0.0, 0.0]));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Transformed<Scalar: Float, T, const DIM: usize, State: SdfState>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    inner: T,
+    matrix: [[Scalar; DIM]; DIM],
+    translation: [Scalar; DIM],
+    inverse_matrix: [[Scalar; DIM]; DIM],
+    inverse_translation: [Scalar; DIM],
+    min_scale: Scalar,
+    _marker: PhantomData<State>,
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Sdf<Scalar, DIM, State>
+    for Transformed<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    #[inline]
+    fn distance_from_slice(&self, point: &[Scalar; DIM]) -> Scalar {
+        self.inner.distance_from_slice(&self.to_local(point)) * self.min_scale
+    }
+
+    #[inline]
+    fn state(&self, point: &[Scalar; DIM]) -> State {
+        self.inner.state(&self.to_local(point))
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Transformed<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    /// Transforms the SDF so that a point `p` of the inner SDF ends up at `matrix * p +
+    /// translation`. The matrix is given as an array of rows.
+    pub fn new(inner: T, matrix: [[Scalar; DIM]; DIM], translation: [Scalar; DIM]) -> Self {
+        let Some(inverse_matrix) = invert(&matrix) else {
+            panic!("Cannot define an affine transform from a singular matrix");
+        };
+
+        Self {
+            inner,
+            matrix,
+            translation,
+            inverse_matrix,
+            inverse_translation: scale(&mul_vector(&inverse_matrix, &translation), -Scalar::one()),
+            min_scale: min_singular_value(&matrix),
+            _marker: PhantomData,
+        }
+    }
+
+    #[inline]
+    pub fn identity(inner: T) -> Self {
+        Self::new(inner, identity(), [Scalar::zero(); DIM])
+    }
+
+    #[inline]
+    pub fn matrix(&self) -> [[Scalar; DIM]; DIM] {
+        self.matrix
+    }
+
+    #[inline]
+    pub fn translation(&self) -> [Scalar; DIM] {
+        self.translation
+    }
+
+    /// Applies another affine transform after this one, without nesting a new transformer.
+    #[inline]
+    pub fn then(self, matrix: [[Scalar; DIM]; DIM], translation: [Scalar; DIM]) -> Self {
+        Self::new(
+            self.inner,
+            mul_matrix(&matrix, &self.matrix),
+            add(&mul_vector(&matrix, &self.translation), &translation),
+        )
+    }
+
+    /// Translates the transformed SDF, composing the translation with the current transform.
+    #[inline]
+    pub fn translate(self, translation: &[Scalar; DIM]) -> Self {
+        self.then(identity(), *translation)
+    }
+
+    /// Scales the transformed SDF, composing the scale with the current transform.
+    #[inline]
+    pub fn scale(self, scale: Scalar) -> Self {
+        self.scale_axes([scale; DIM])
+    }
+
+    /// Scales the transformed SDF along each axis, composing the scale with the current transform.
+    #[inline]
+    pub fn scale_axes(self, scale: impl Into<[Scalar; DIM]>) -> Self {
+        let scale = scale.into();
+        let matrix = std::array::from_fn(|i| {
+            std::array::from_fn(|j| if i == j { scale[i] } else { Scalar::zero() })
+        });
+
+        self.then(matrix, [Scalar::zero(); DIM])
+    }
+
+    #[inline]
+    fn to_local(&self, point: &[Scalar; DIM]) -> [Scalar; DIM] {
+        add(
+            &mul_vector(&self.inverse_matrix, point),
+            &self.inverse_translation,
+        )
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> Transformed<Scalar, T, DIM, State>
+where
+    T: AffineTransformer<Scalar, DIM, State>,
+{
+    /// Merges the affine transformer wrapped by this transform into it, removing one level of
+    /// nesting.
+    #[inline]
+    pub fn collapse(self) -> Transformed<Scalar, T::Inner, DIM, State> {
+        let inner = self.inner.into_transformed();
+
+        inner.then(self.matrix, self.translation)
+    }
+}
+
+impl<Scalar: Float, T, State: SdfState> Transformed<Scalar, T, 3, State>
+where
+    T: Sdf<Scalar, 3, State>,
+{
+    /// Rotates the transformed SDF, composing the rotation with the current transform.
+    #[inline]
+    pub fn rotate_3d(self, rotation: impl Into<Quaternion<Scalar>>) -> Self {
+        let matrix = rotation.into().normalize().to_matrix();
+
+        self.then(matrix, [Scalar::zero(); 3])
+    }
+}
+
+/// A transformer that applies an affine transform to the SDF it wraps, and can therefore be turned
+/// into a [`Transformed`] SDF. This is used to collapse chains of transformers into a single one.
+pub trait AffineTransformer<Scalar: Float, const DIM: usize, State: SdfState>:
+    Sdf<Scalar, DIM, State> + Sized
+{
+    type Inner: Sdf<Scalar, DIM, State>;
+
+    fn into_transformed(self) -> Transformed<Scalar, Self::Inner, DIM, State>;
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> AffineTransformer<Scalar, DIM, State>
+    for Transformed<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    type Inner = T;
+
+    #[inline]
+    fn into_transformed(self) -> Self {
+        self
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> AffineTransformer<Scalar, DIM, State>
+    for Translated<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    type Inner = T;
+
+    #[inline]
+    fn into_transformed(self) -> Transformed<Scalar, T, DIM, State> {
+        Transformed::identity(self.inner).translate(&self.inverse_translation.map(|axis| -axis))
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> AffineTransformer<Scalar, DIM, State>
+    for Scaled<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    type Inner = T;
+
+    #[inline]
+    fn into_transformed(self) -> Transformed<Scalar, T, DIM, State> {
+        Transformed::identity(self.inner).scale(self.scale)
+    }
+}
+
+impl<Scalar: Float, T, const DIM: usize, State: SdfState> AffineTransformer<Scalar, DIM, State>
+    for ScaledAxes<Scalar, T, DIM, State>
+where
+    T: Sdf<Scalar, DIM, State>,
+{
+    type Inner = T;
+
+    #[inline]
+    fn into_transformed(self) -> Transformed<Scalar, T, DIM, State> {
+        let scale = self.scale();
+
+        Transformed::identity(self.inner).scale_axes(scale)
+    }
+}
+
+impl<Scalar: Float, T, State: SdfState> AffineTransformer<Scalar, 3, State>
+    for Rotated<Scalar, T, State>
+where
+    T: Sdf<Scalar, 3, State>,
+{
+    type Inner = T;
+
+    #[inline]
+    fn into_transformed(self) -> Transformed<Scalar, T, 3, State> {
+        Transformed::new(self.inner, self.rotation.to_matrix(), [Scalar::zero(); 3])
+    }
+}
+
+#[cfg(feature = "glam")]
+impl<Scalar: Float, T, State: SdfState> AffineTransformer<Scalar, 2, State>
+    for Rotated2d<Scalar, T, State>
+where
+    T: Sdf<Scalar, 2, State>,
+{
+    type Inner = T;
+
+    #[inline]
+    fn into_transformed(self) -> Transformed<Scalar, T, 2, State> {
+        let matrix = [[self.cos, -self.sin], [self.sin, self.cos]];
+
+        Transformed::new(self.inner, matrix, [Scalar::zero(); 2])
+    }
+}
+
+#[cfg(feature = "glam")]
+impl<T, State: SdfState> AffineTransformer<f32, 3, State> for Rotated3d<T, State>
+where
+    T: Sdf<f32, 3, State>,
+{
+    type Inner = T;
+
+    #[inline]
+    fn into_transformed(self) -> Transformed<f32, T, 3, State> {
+        // glam matrices are stored as columns, and the inverse of a rotation is its transpose.
+        let matrix = glam::Mat3::from_quat(self.inverse_rotation).to_cols_array_2d();
+
+        Transformed::new(self.inner, matrix, [0.0; 3])
+    }
+}
+
+#[cfg(feature = "glam")]
+impl<T, State: SdfState> AffineTransformer<f64, 3, State> for DRotated3d<T, State>
+where
+    T: Sdf<f64, 3, State>,
+{
+    type Inner = T;
+
+    #[inline]
+    fn into_transformed(self) -> Transformed<f64, T, 3, State> {
+        // glam matrices are stored as columns, and the inverse of a rotation is its transpose.
+        let matrix = glam::DMat3::from_quat(self.inverse_rotation).to_cols_array_2d();
+
+        Transformed::new(self.inner, matrix, [0.0; 3])
+    }
+}
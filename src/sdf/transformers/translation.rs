@@ -7,8 +7,8 @@ pub struct Translated<Scalar: Float, T, const DIM: usize, State: SdfState>
 where
     T: Sdf<Scalar, DIM, State>,
 {
-    inner: T,
-    inverse_translation: [Scalar; DIM],
+    pub(super) inner: T,
+    pub(super) inverse_translation: [Scalar; DIM],
     _marker: PhantomData<State>,
 }
 